@@ -0,0 +1,132 @@
+use crate::{AocError, Part, Result, input::column_of};
+use std::collections::BTreeMap;
+
+/// Confirmed answers for one input. Either part may be unknown.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Parses the small TOML subset answer files are written in:
+///
+/// ```toml
+/// # answers for data/input.txt
+/// part1 = 1158
+/// part2 = "6860"
+///
+/// [example]
+/// part1 = 3
+/// ```
+///
+/// Keys before any `[table]` header are filed under the empty name.
+pub fn parse(text: &str) -> Result<BTreeMap<String, Answers>> {
+    let mut tables = BTreeMap::new();
+    let mut current = String::new();
+    for (i, raw) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = raw.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| AocError::parse(line_no, 1, line, "unterminated table header"))?;
+            current = name.trim().to_string();
+            tables
+                .entry(current.clone())
+                .or_insert_with(Answers::default);
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| AocError::parse(line_no, 1, line, "expected key = value"))?;
+        let (key, value) = (key.trim(), value.trim());
+        let value_column = column_of(raw, value);
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => quoted.strip_suffix('"').ok_or_else(|| {
+                AocError::parse(line_no, value_column, value, "unterminated string")
+            })?,
+            None if is_integer(value) => value,
+            None => {
+                return Err(AocError::parse(
+                    line_no,
+                    value_column,
+                    value,
+                    "expected an integer or a quoted string",
+                ));
+            }
+        };
+        let answers = tables
+            .entry(current.clone())
+            .or_insert_with(Answers::default);
+        match key {
+            "part1" => answers.part1 = Some(value.to_string()),
+            "part2" => answers.part2 = Some(value.to_string()),
+            other => {
+                return Err(AocError::parse(
+                    line_no,
+                    1,
+                    other,
+                    "expected part1 or part2",
+                ));
+            }
+        }
+    }
+    Ok(tables)
+}
+
+/// An optional `-` and then at least one digit.
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Part,
+        answers::{Answers, parse},
+    };
+
+    #[test]
+    fn parses_top_level_and_tables() {
+        let tables = parse(
+            "# confirmed\npart1 = 1158\npart2 = \"6860\" # trailing\n\n[example]\npart1 = -3\n",
+        )
+        .expect("");
+        assert_eq!(
+            tables[""],
+            Answers {
+                part1: Some("1158".to_string()),
+                part2: Some("6860".to_string()),
+            }
+        );
+        assert_eq!(tables["example"].get(Part::One), Some("-3"));
+        assert_eq!(tables["example"].get(Part::Two), None);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let err = |text| parse(text).expect_err("").position();
+        assert_eq!(err("part1 = 12\npart3 = 4"), Some((2, 1)));
+        assert_eq!(err("part1 = twelve"), Some((1, 9)));
+        assert_eq!(err("[example\npart1 = 1"), Some((1, 1)));
+        assert_eq!(err("part1 = \"12"), Some((1, 9)));
+        // the value's column, even when its text also appears in the key
+        assert_eq!(err("part1 = t"), Some((1, 9)));
+        assert_eq!(err("part1 = --"), Some((1, 9)));
+        assert_eq!(err("part1 = 1-2"), Some((1, 9)));
+        assert_eq!(err("part1 = -"), Some((1, 9)));
+    }
+}
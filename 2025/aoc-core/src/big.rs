@@ -0,0 +1,126 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign},
+};
+
+/// An unsigned integer of any size, for counts that outgrow `u128`. It only does what counting
+/// needs: adding up and printing.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigUint {
+    /// Base 2⁶⁴ digits, least significant first, with no trailing zeros.
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> Self {
+        let mut limbs = vec![n as u64, (n >> 64) as u64];
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from(n as u128)
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        let mut carry = false;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let (sum, over) = limb.overflowing_add(rhs.limbs.get(i).copied().unwrap_or(0));
+            let (sum, carried) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = over || carried;
+            if !carry && i >= rhs.limbs.len() {
+                break;
+            }
+        }
+        if carry {
+            self.limbs.push(1);
+        }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(mut self, rhs: BigUint) -> BigUint {
+        self += &rhs;
+        self
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // peel off 19 decimal digits at a time, the most that fit in a u64
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut limbs = self.limbs.clone();
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let cur = (rem << 64) | *limb as u128;
+                *limb = (cur / CHUNK) as u64;
+                rem = cur % CHUNK;
+            }
+            chunks.push(rem as u64);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+        let digits = match chunks.split_last() {
+            None => "0".to_string(),
+            Some((first, rest)) => rest
+                .iter()
+                .rev()
+                .fold(first.to_string(), |s, chunk| format!("{s}{chunk:019}")),
+        };
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::big::BigUint;
+
+    #[test]
+    fn adds_past_u128() {
+        let max = BigUint::from(u128::MAX);
+        assert_eq!(max.to_string(), u128::MAX.to_string());
+        let doubled = max.clone() + max;
+        assert_eq!(
+            doubled.to_string(),
+            "680564733841876926926749214863536422910"
+        );
+        let mut n = BigUint::default();
+        assert!(n.is_zero());
+        assert_eq!(n.to_string(), "0");
+        n += &BigUint::from(7u64);
+        assert_eq!(format!("{n:>3}"), "  7");
+    }
+
+    #[test]
+    fn doubles_many_times() {
+        let mut n = BigUint::from(1u64);
+        for _ in 0..200 {
+            n = n.clone() + n;
+        }
+        assert_eq!(
+            n.to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Result, input::Source};
+use std::{collections::HashMap, env, error::Error, io::BufRead, path::Path, process::ExitCode};
+
+/// The command line of a day's example program: `--name` switches and `--name=value` options
+/// from those it takes, and at most one input path, `-` for stdin.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Args {
+    options: HashMap<String, Option<String>>,
+    input: Option<String>,
+}
+
+impl Args {
+    /// Reads `args`, rejecting any option not named in `known`.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        known: &[&str],
+    ) -> std::result::Result<Args, String> {
+        let mut parsed = Args::default();
+        for arg in args {
+            if arg.starts_with("--") {
+                let (name, value) = match arg.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (arg.as_str(), None),
+                };
+                if !known.contains(&name) {
+                    return Err(format!("unknown option {name}"));
+                }
+                parsed.options.insert(name.to_string(), value);
+            } else if parsed.input.replace(arg).is_some() {
+                return Err("expected at most one input path".to_string());
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Whether `--name` was given, with or without a value.
+    pub fn switch(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// The value given as `--name=value`.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name)?.as_deref()
+    }
+
+    /// Where the input comes from, falling back like the runner to `AOC_INPUT` and then the
+    /// `data/input.txt` of the crate in `manifest_dir`.
+    pub fn source(&self, manifest_dir: &str) -> Source {
+        let default = Path::new(manifest_dir).join("data/input.txt");
+        Source::from_env(self.input.as_deref(), default)
+    }
+}
+
+/// Runs a day's example program: reads its command line, taking the options in `known`, reads
+/// its input with `parse` and hands both to `body`. Any error is printed and fails the program.
+///
+/// Pass `env!("CARGO_MANIFEST_DIR")` as `manifest_dir` so the input defaults to the day's own.
+pub fn run<T, P, F>(manifest_dir: &str, known: &[&str], parse: P, body: F) -> ExitCode
+where
+    P: FnOnce(Box<dyn BufRead>) -> Result<T>,
+    F: FnOnce(&Args, T) -> std::result::Result<(), Box<dyn Error>>,
+{
+    let outcome = Args::parse(env::args().skip(1), known).and_then(|args| {
+        let source = args.source(manifest_dir);
+        let parsed = source
+            .open()
+            .map_err(|e| format!("cannot read {source}: {e}"))
+            .and_then(|input| parse(input).map_err(|e| format!("{source}: {e}")))?;
+        body(&args, parsed).map_err(|e| e.to_string())
+    });
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::Args;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|a| a.to_string()), &["--rolls", "--out"])
+    }
+
+    #[test]
+    fn parses_options_and_input() {
+        let args = parse(&["--rolls", "floor.txt", "--out=frames"]).expect("");
+        assert!(args.switch("--rolls"));
+        assert_eq!(args.value("--out"), Some("frames"));
+        assert_eq!(args.value("--rolls"), None);
+        assert_eq!(args.input.as_deref(), Some("floor.txt"));
+        assert!(!parse(&[]).expect("").switch("--rolls"));
+    }
+
+    #[test]
+    fn rejects_unknown_options_and_extra_paths() {
+        assert_eq!(parse(&["--ppm"]), Err("unknown option --ppm".to_string()));
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    grid::Grid,
+    neighborhood::Neighborhood,
+    point::{Direction, Point},
+};
+use std::collections::VecDeque;
+
+/// One connected group of cells.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Component {
+    /// The label its cells carry, which is also its place in [`Labels::components`].
+    pub id: usize,
+    pub size: usize,
+    /// The top left corner of the smallest box holding every cell.
+    pub min: Point<i32>,
+    /// The bottom right corner of that box.
+    pub max: Point<i32>,
+    /// Cell sides facing a cell outside the component or the edge of the grid.
+    pub perimeter: usize,
+}
+
+/// Every cell's component, `None` for cells that belong to none, with the components found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Labels {
+    pub grid: Grid<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+/// The cells `member` accepts that can be reached from `start` through `neighborhood` without
+/// leaving them, `start` first. Empty when `start` itself is not a member.
+pub fn flood_fill<T, F>(
+    grid: &Grid<T>,
+    start: Point<i32>,
+    neighborhood: &Neighborhood,
+    member: F,
+) -> Vec<Point<i32>>
+where
+    F: Fn(&T) -> bool,
+{
+    let mut seen = grid.map(|_, _| false);
+    fill(grid, start, neighborhood, &member, &mut seen)
+}
+
+fn fill<T, F>(
+    grid: &Grid<T>,
+    start: Point<i32>,
+    neighborhood: &Neighborhood,
+    member: F,
+    seen: &mut Grid<bool>,
+) -> Vec<Point<i32>>
+where
+    F: Fn(&T) -> bool,
+{
+    let Some(start) = grid.resolve(start).filter(|&p| member(&grid[p])) else {
+        return Vec::new();
+    };
+    seen[start] = true;
+    let mut filled = vec![start];
+    let mut queue = VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        for n in neighborhood.neighbors(grid, p) {
+            if !seen[n] && member(&grid[n]) {
+                seen[n] = true;
+                filled.push(n);
+                queue.push_back(n);
+            }
+        }
+    }
+    filled
+}
+
+/// Splits the cells `member` accepts into groups connected through `neighborhood`, numbered
+/// from 0 in the order their first cell appears row by row.
+pub fn label<T, F>(grid: &Grid<T>, neighborhood: &Neighborhood, member: F) -> Labels
+where
+    F: Fn(&T) -> bool,
+{
+    let mut seen = grid.map(|_, _| false);
+    let mut labels = grid.map(|_, _| None);
+    let mut components = Vec::new();
+    for p in grid.points() {
+        if seen[p] {
+            continue;
+        }
+        let cells = fill(grid, p, neighborhood, &member, &mut seen);
+        if cells.is_empty() {
+            continue;
+        }
+        let id = components.len();
+        let mut component = Component {
+            id,
+            size: cells.len(),
+            min: p,
+            max: p,
+            perimeter: 0,
+        };
+        for &cell in &cells {
+            labels[cell] = Some(id);
+            component.min = Point::new(component.min.y.min(cell.y), component.min.x.min(cell.x));
+            component.max = Point::new(component.max.y.max(cell.y), component.max.x.max(cell.x));
+        }
+        component.perimeter = cells
+            .iter()
+            .flat_map(|&cell| Direction::CARDINAL.map(|d| cell + d.offset()))
+            // checked against the grid itself, as a wrapped or clamped lookup would find a cell
+            .filter(|&side| !labels.contains(side) || labels[side] != Some(id))
+            .count();
+        components.push(component);
+    }
+    Labels {
+        grid: labels,
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{flood_fill, label},
+        grid::{Edge, Grid},
+        input::reader,
+        neighborhood::Neighborhood,
+        point::Point,
+    };
+
+    const CLUSTERS: &str = "\
+        @@..@
+        @...@
+        .@...
+        .@@.@";
+
+    fn clusters() -> Grid<bool> {
+        Grid::parse(reader(CLUSTERS), |c| Some(c == '@')).expect("")
+    }
+
+    #[test]
+    fn fills_from_a_cell() {
+        let grid = clusters();
+        let roll = |&cell: &bool| cell;
+        let corner = flood_fill(&grid, Point::new(0, 0), &Neighborhood::von_neumann(), roll);
+        assert_eq!(corner.len(), 3);
+        let joined = flood_fill(&grid, Point::new(2, 1), &Neighborhood::moore(), roll);
+        assert_eq!(joined.len(), 6);
+        assert!(flood_fill(&grid, Point::new(0, 2), &Neighborhood::moore(), roll).is_empty());
+    }
+
+    #[test]
+    fn labels_components() {
+        let grid = clusters();
+        let four = label(&grid, &Neighborhood::von_neumann(), |&cell| cell);
+        let sizes: Vec<usize> = four.components.iter().map(|c| c.size).collect();
+        assert_eq!(sizes, vec![3, 2, 3, 1]);
+        assert_eq!(four.grid[Point::new(3, 1)], Some(2));
+        assert_eq!(four.grid[Point::new(0, 2)], None);
+        let bottom = &four.components[2];
+        assert_eq!(
+            (bottom.min, bottom.max),
+            (Point::new(2, 1), Point::new(3, 2))
+        );
+        assert_eq!(bottom.perimeter, 8);
+        assert_eq!(four.components[0].perimeter, 8);
+
+        // diagonals join the middle group to the top left one
+        let eight = label(&grid, &Neighborhood::moore(), |&cell| cell);
+        let sizes: Vec<usize> = eight.components.iter().map(|c| c.size).collect();
+        assert_eq!(sizes, vec![6, 2, 1]);
+        assert_eq!(eight.components[0].perimeter, 16);
+        assert_eq!(eight.components[0].max, Point::new(3, 2));
+
+        // a clamped edge still has sides facing off the grid
+        let clamped = label(
+            &grid.with_edge(Edge::Clamp),
+            &Neighborhood::von_neumann(),
+            |&cell| cell,
+        );
+        assert_eq!(clamped.components, four.components);
+    }
+}
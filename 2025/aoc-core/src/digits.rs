@@ -0,0 +1,64 @@
+use crate::{
+    AocError, Result,
+    input::{column_of, numbered_lines},
+};
+use std::io::BufRead;
+
+const BASE: u64 = 10;
+
+/// Folds base 10 digits, most significant first, into the number they spell.
+pub fn from_digits<T: Copy + Into<u64>>(digits: &[T]) -> u64 {
+    digits.iter().fold(0, |acc, &d| acc * BASE + d.into())
+}
+
+/// Splits each trimmed line of the reader into its digits.
+pub fn digit_lines(reader: impl BufRead) -> impl Iterator<Item = Result<Vec<u32>>> {
+    numbered_lines(reader).map(|numbered| {
+        let (line_no, line) = numbered?;
+        let trimmed = line.trim();
+        let start = column_of(&line, trimmed);
+        trimmed
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(BASE as u32).ok_or_else(|| {
+                    AocError::parse(line_no, start + i, &c.to_string(), "not a digit")
+                })
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        digits::{digit_lines, from_digits},
+        input::reader,
+    };
+
+    #[test]
+    fn folds_digits() {
+        assert_eq!(from_digits::<u32>(&[]), 0);
+        assert_eq!(from_digits(&[4u32, 0, 7]), 407);
+        assert_eq!(from_digits(&[9u8; 12]), 999_999_999_999);
+    }
+
+    #[test]
+    fn splits_lines() {
+        let lines: Vec<Vec<u32>> = digit_lines(reader("12\n 30 \n"))
+            .map(|l| l.expect(""))
+            .collect();
+        assert_eq!(lines, vec![vec![1, 2], vec![3, 0]]);
+        let err = digit_lines(reader("12\n 1x"))
+            .nth(1)
+            .expect("")
+            .expect_err("");
+        assert_eq!(err.position(), Some((2, 3)));
+        // columns count characters, not bytes
+        let err = digit_lines(reader("\u{a0}1x"))
+            .next()
+            .expect("")
+            .expect_err("");
+        assert_eq!(err.position(), Some((1, 3)));
+    }
+}
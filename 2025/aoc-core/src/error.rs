@@ -0,0 +1,149 @@
+use std::{error::Error, fmt, io};
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Why a puzzle input could not be solved. Lines and columns are 1-based and count characters.
+#[derive(Debug)]
+pub enum AocError {
+    /// Text that could not be read as the value the puzzle expects there.
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    /// Input that parses but does not fit together, such as ragged grid rows.
+    Shape {
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    Io(io::Error),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, text: &str, reason: impl fmt::Display) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn shape(line: usize, column: usize, text: &str, reason: impl fmt::Display) -> Self {
+        AocError::Shape {
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Moves an error reported against a single-line fragment of the input to where that
+    /// fragment actually starts.
+    pub fn shifted(self, at_line: usize, at_column: usize) -> Self {
+        match self {
+            AocError::Parse {
+                column,
+                text,
+                reason,
+                ..
+            } => AocError::Parse {
+                line: at_line,
+                column: at_column + column - 1,
+                text,
+                reason,
+            },
+            AocError::Shape {
+                column,
+                text,
+                reason,
+                ..
+            } => AocError::Shape {
+                line: at_line,
+                column: at_column + column - 1,
+                text,
+                reason,
+            },
+            io => io,
+        }
+    }
+
+    /// The (line, column) the error points at, if it came from the input's contents.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            AocError::Parse { line, column, .. } | AocError::Shape { line, column, .. } => {
+                Some((*line, *column))
+            }
+            AocError::Io(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "line {line}, column {column}: cannot parse {text:?}: {reason}"
+            ),
+            AocError::Shape {
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "line {line}, column {column}: bad shape at {text:?}: {reason}"
+            ),
+            AocError::Io(e) => write!(f, "cannot read input: {e}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AocError;
+
+    #[test]
+    fn reports_position_and_text() {
+        let e = AocError::parse(3, 7, "x1", "invalid digit found in string");
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 7: cannot parse \"x1\": invalid digit found in string"
+        );
+        let e = AocError::shape(2, 1, "@@.", "expected 4 columns");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: bad shape at \"@@.\": expected 4 columns"
+        );
+    }
+
+    #[test]
+    fn shifts_fragment_errors() {
+        let e = AocError::parse(1, 3, "-", "empty").shifted(4, 10);
+        assert_eq!(e.position(), Some((4, 12)));
+    }
+}
@@ -0,0 +1,135 @@
+//! Example inputs kept on disk and checked as tests.
+//!
+//! A day keeps each example as `data/examples/<name>.txt` and its expected answers in
+//! `data/examples/answers.toml`, one `[<name>]` table per example. The day's build script calls
+//! [`generate_tests`], and `example_tests!(DayN)` in its `lib.rs` turns every example into a
+//! `#[test]`, so a new edge case needs no Rust code. Examples without recorded answers are
+//! generated as ignored tests.
+
+use crate::{Solution, answers, solution::answer};
+use std::{env, fmt::Write, fs, path::Path};
+
+pub const EXAMPLES_DIR: &str = "data/examples";
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Build script entry point: writes one test per example to `$OUT_DIR/examples.rs`.
+pub fn generate_tests() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("run from a build script");
+    let out_dir = env::var("OUT_DIR").expect("run from a build script");
+    let dir = Path::new(&manifest_dir).join(EXAMPLES_DIR);
+    println!("cargo:rerun-if-changed={}", dir.display());
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests_for(&dir))
+        .expect("cannot write generated example tests");
+}
+
+/// The generated test source for every example in `dir`.
+pub fn tests_for(dir: &Path) -> String {
+    let recorded = fs::read_to_string(dir.join(ANSWERS_FILE))
+        .map(|text| answers::parse(&text).expect("malformed examples answers.toml"))
+        .unwrap_or_default();
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|file| file.strip_suffix(".txt").map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    let mut source = String::new();
+    for name in names {
+        let input = dir.join(format!("{name}.txt"));
+        if recorded
+            .get(&name)
+            .is_none_or(|a| a.part1.is_none() && a.part2.is_none())
+        {
+            source.push_str("#[ignore = \"no answers recorded\"]\n");
+        }
+        writeln!(
+            source,
+            "#[test]\nfn {}() {{\n    ::aoc_core::fixtures::check::<Solution>({name:?}, include_str!({:?}), include_str!({:?}));\n}}\n",
+            test_name(&name),
+            input.display(),
+            dir.join(ANSWERS_FILE).display(),
+        )
+        .expect("writing to a String");
+    }
+    source
+}
+
+fn test_name(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Solves `input` and asserts every answer recorded for the example `name`.
+pub fn check<S: Solution>(name: &str, input: &str, answers_toml: &str) {
+    let recorded = answers::parse(answers_toml)
+        .expect("malformed examples answers.toml")
+        .remove(name)
+        .unwrap_or_else(|| panic!("no answers recorded for example {name}"));
+    let parsed =
+        S::parse(input.as_bytes()).unwrap_or_else(|e| panic!("example {name} does not parse: {e}"));
+    for part in crate::Part::BOTH {
+        if let Some(expected) = recorded.get(part) {
+            assert_eq!(
+                answer::<S>(&parsed, part),
+                expected,
+                "example {name} part {part}"
+            );
+        }
+    }
+}
+
+/// Includes the tests [`generate_tests`] wrote for the day's examples.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            type Solution = $solution;
+
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{ANSWERS_FILE, test_name, tests_for};
+    use std::{env, fs};
+
+    #[test]
+    fn names_tests() {
+        assert_eq!(test_name("example"), "example");
+        assert_eq!(test_name("wide-grid.v2"), "wide_grid_v2");
+        assert_eq!(test_name("1row"), "_1row");
+    }
+
+    #[test]
+    fn generates_a_test_per_example() {
+        let dir = env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("");
+        fs::write(dir.join("example.txt"), "1\n").expect("");
+        fs::write(dir.join("pending.txt"), "2\n").expect("");
+        fs::write(dir.join("notes.md"), "ignored").expect("");
+        fs::write(dir.join("empty.txt"), "3\n").expect("");
+        fs::write(dir.join(ANSWERS_FILE), "[example]\npart1 = 1\n[empty]\n").expect("");
+        let source = tests_for(&dir);
+        fs::remove_dir_all(&dir).expect("");
+        assert_eq!(source.matches("#[test]").count(), 3);
+        assert_eq!(source.matches("#[ignore").count(), 2);
+        assert!(source.contains("fn example()"));
+        assert!(source.contains("#[ignore = \"no answers recorded\"]\n#[test]\nfn pending()"));
+        assert!(!source.contains("notes"));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    AocError, Result,
+    input::{column_of, numbered_lines},
+    point::Point,
+};
+use std::{
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular map of cells, stored row by row and addressed by [`Point`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+    edge: Edge<T>,
+}
+
+/// What a lookup past the edge of a grid finds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Edge<T> {
+    /// Nothing: only points on the grid have cells.
+    Bounded,
+    /// The opposite side, as if the grid were wrapped around a torus.
+    Wrap,
+    /// The nearest cell on the edge.
+    Clamp,
+    /// The same fixed value everywhere off the grid, which cannot be changed.
+    Outside(T),
+}
+
+/// Reads a character map one row per line, turning each character into a cell with `cell`, for
+/// maps too big to keep whole. Surrounding whitespace is ignored; a row wider or narrower than
+/// the first is a shape error and a character `cell` rejects a parse error.
+pub fn parse_rows<T, F>(reader: impl BufRead, mut cell: F) -> impl Iterator<Item = Result<Vec<T>>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let mut width = None;
+    numbered_lines(reader).map(move |numbered| {
+        let (line_no, line) = numbered?;
+        let trimmed = line.trim();
+        let start = column_of(&line, trimmed);
+        let row_width = trimmed.chars().count();
+        let width = *width.get_or_insert(row_width);
+        if row_width != width {
+            return Err(AocError::shape(
+                line_no,
+                start,
+                trimmed,
+                format!("expected {width} cells like the first row, found {row_width}"),
+            ));
+        }
+        trimmed
+            .chars()
+            .enumerate()
+            .map(|(column, char)| {
+                cell(char).ok_or_else(|| {
+                    AocError::parse(
+                        line_no,
+                        start + column,
+                        &char.to_string(),
+                        "not a cell of this grid",
+                    )
+                })
+            })
+            .collect()
+    })
+}
+
+impl<T> Grid<T> {
+    /// Reads a character map like [`parse_rows`] and keeps every row.
+    pub fn parse<F>(reader: impl BufRead, cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for row in parse_rows(reader, cell) {
+            let row = row?;
+            width = row.len();
+            height += 1;
+            cells.extend(row);
+        }
+        Ok(Grid {
+            cells,
+            height,
+            width,
+            edge: Edge::Bounded,
+        })
+    }
+
+    /// Lays `cells` out row by row, `width` to a row. Panics unless they fill whole rows.
+    pub fn from_cells(cells: Vec<T>, width: usize) -> Grid<T> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(height * width, cells.len(), "cells must fill whole rows");
+        Grid {
+            cells,
+            height,
+            width,
+            edge: Edge::Bounded,
+        }
+    }
+
+    /// The same grid with lookups past its edge following `edge`. Grids start out bounded.
+    pub fn with_edge(self, edge: Edge<T>) -> Grid<T> {
+        Grid { edge, ..self }
+    }
+
+    pub fn edge(&self) -> &Edge<T> {
+        &self.edge
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, p: Point<i32>) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    /// The position of `p` in the row-major cells, or `None` when it lies outside the grid.
+    pub fn index_of(&self, p: Point<i32>) -> Option<usize> {
+        p.to_index(self.width).filter(|&i| i < self.cells.len())
+    }
+
+    /// The point at `index` in the row-major cells.
+    pub fn point_of(&self, index: usize) -> Point<i32> {
+        Point::from_index(index, self.width).expect("grid sides fit in an i32")
+    }
+
+    /// The point on the grid that a lookup of `p` lands on under the grid's [`Edge`], or
+    /// `None` when it lands on no cell.
+    pub fn resolve(&self, p: Point<i32>) -> Option<Point<i32>> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let (h, w) = (self.height as i32, self.width as i32);
+        match self.edge {
+            Edge::Wrap => Some(Point::new(p.y.rem_euclid(h), p.x.rem_euclid(w))),
+            Edge::Clamp => Some(Point::new(p.y.clamp(0, h - 1), p.x.clamp(0, w - 1))),
+            Edge::Bounded | Edge::Outside(_) => self.contains(p).then_some(p),
+        }
+    }
+
+    /// The cell a lookup of `p` finds, following the grid's [`Edge`] off the grid.
+    pub fn get(&self, p: Point<i32>) -> Option<&T> {
+        match (self.resolve(p), &self.edge) {
+            (Some(on), _) => self.index_of(on).map(|i| &self.cells[i]),
+            (None, Edge::Outside(value)) => Some(value),
+            (None, _) => None,
+        }
+    }
+
+    /// Like [`Grid::get`], except that a fixed outside value cannot be changed.
+    pub fn get_mut(&mut self, p: Point<i32>) -> Option<&mut T> {
+        let i = self.resolve(p).and_then(|on| self.index_of(on))?;
+        Some(&mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<i32>> + use<T> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |i| Point::from_index(i, width).expect("grid sides fit in an i32"))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i32>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same shape holding what `f` makes of each point and cell. It wraps or
+    /// clamps like this one, but a fixed outside value has no counterpart, so it is bounded
+    /// instead.
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Point<i32>, &T) -> U,
+    {
+        Grid {
+            cells: self.iter().map(|(p, cell)| f(p, cell)).collect(),
+            height: self.height,
+            width: self.width,
+            edge: match self.edge {
+                Edge::Wrap => Edge::Wrap,
+                Edge::Clamp => Edge::Clamp,
+                Edge::Bounded | Edge::Outside(_) => Edge::Bounded,
+            },
+        }
+    }
+}
+
+impl<T> Index<Point<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<i32>) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point<i32>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<i32>) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{Edge, Grid},
+        input::reader,
+        point::Point,
+    };
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(reader(input), |c| c.to_digit(10)).expect("")
+    }
+
+    #[test]
+    fn parses_and_indexes() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&4));
+        assert_eq!(grid[Point::new(0, 2)], 3);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.point_of(5), Point::new(1, 2));
+        assert_eq!(grid.index_of(Point::new(1, 2)), Some(5));
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let mut grid = digits("123\n456");
+        *grid.get_mut(Point::new(0, 0)).expect("") = 7;
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[7, 2, 3], &[4, 5, 6]]
+        );
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![7, 4], vec![2, 5], vec![3, 6]]);
+        let odd = grid.map(|_, &n| n % 2 == 1);
+        assert_eq!(odd.row(1), &[false, true, false]);
+        assert_eq!(odd.iter().filter(|(_, odd)| **odd).count(), 3);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let err = |input| {
+            Grid::parse(reader(input), |c| c.to_digit(10))
+                .expect_err("")
+                .position()
+        };
+        assert_eq!(err("12\n345"), Some((2, 1)));
+        assert_eq!(err("12\n 3x"), Some((2, 3)));
+    }
+
+    #[test]
+    fn follows_edges() {
+        let grid = digits("123\n456");
+        let past = Point::new(-1, 3);
+        assert_eq!(grid.get(past), None);
+        let wrapped = grid.clone().with_edge(Edge::Wrap);
+        assert_eq!(wrapped.resolve(past), Some(Point::new(1, 0)));
+        assert_eq!(wrapped[Point::new(5, -4)], 6);
+        let clamped = grid.clone().with_edge(Edge::Clamp);
+        assert_eq!(clamped.resolve(past), Some(Point::new(0, 2)));
+        assert_eq!(clamped[Point::new(9, -9)], 4);
+        let mut walled = grid.with_edge(Edge::Outside(0));
+        assert_eq!((walled.resolve(past), walled.get(past)), (None, Some(&0)));
+        assert_eq!(walled.get_mut(past), None);
+        assert_eq!(walled.map(|_, &n| n).get(past), None);
+    }
+}
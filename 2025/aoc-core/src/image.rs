@@ -0,0 +1,220 @@
+use crate::{grid::Grid, point::Point, render::Rgb};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// An image file format the exporter can write without any outside crates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Binary PPM (`P6`), which most viewers and `ffmpeg` read.
+    Ppm,
+    /// PNG with the image data stored uncompressed.
+    Png,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+
+    /// Encodes `grid` with each cell drawn as a `scale` by `scale` square of its `color`.
+    pub fn encode<T, F>(self, grid: &Grid<T>, scale: usize, color: F) -> Vec<u8>
+    where
+        F: Fn(Point<i32>, &T) -> Rgb,
+    {
+        let pixels = Pixels::of(grid, scale, color);
+        match self {
+            Format::Ppm => ppm(&pixels),
+            Format::Png => png(&pixels),
+        }
+    }
+}
+
+/// Writes each of `frames` into `dir` as `frame-0000.<ext>`, `frame-0001.<ext>` and so on,
+/// ready to be stitched into an animation. Returns the paths written.
+pub fn write_frames<'a, T, F>(
+    dir: &Path,
+    format: Format,
+    frames: impl IntoIterator<Item = &'a Grid<T>>,
+    scale: usize,
+    color: F,
+) -> io::Result<Vec<PathBuf>>
+where
+    T: 'a,
+    F: Fn(Point<i32>, &T) -> Rgb,
+{
+    fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for (i, frame) in frames.into_iter().enumerate() {
+        let path = dir.join(format!("frame-{i:04}.{}", format.extension()));
+        fs::write(&path, format.encode(frame, scale, &color))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Rows of RGB bytes, three to a pixel.
+struct Pixels {
+    width: usize,
+    height: usize,
+    rows: Vec<Vec<u8>>,
+}
+
+impl Pixels {
+    fn of<T, F>(grid: &Grid<T>, scale: usize, color: F) -> Pixels
+    where
+        F: Fn(Point<i32>, &T) -> Rgb,
+    {
+        let mut rows = Vec::with_capacity(grid.height() * scale);
+        for (y, cells) in grid.rows().enumerate() {
+            let mut row = Vec::with_capacity(cells.len() * scale * 3);
+            for (x, cell) in cells.iter().enumerate() {
+                let Rgb(r, g, b) = color(Point::new(y as i32, x as i32), cell);
+                for _ in 0..scale {
+                    row.extend([r, g, b]);
+                }
+            }
+            rows.extend(std::iter::repeat_n(row, scale));
+        }
+        Pixels {
+            width: grid.width() * scale,
+            height: grid.height() * scale,
+            rows,
+        }
+    }
+}
+
+fn ppm(pixels: &Pixels) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", pixels.width, pixels.height).into_bytes();
+    for row in &pixels.rows {
+        out.extend(row);
+    }
+    out
+}
+
+fn png(pixels: &Pixels) -> Vec<u8> {
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    let mut header = Vec::with_capacity(13);
+    header.extend((pixels.width as u32).to_be_bytes());
+    header.extend((pixels.height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, deflate, no filtering, no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+    chunk(&mut out, b"IHDR", &header);
+    let mut scanlines = Vec::with_capacity(pixels.height * (pixels.width * 3 + 1));
+    for row in &pixels.rows {
+        // each scanline starts with its filter type, here none
+        scanlines.push(0);
+        scanlines.extend(row);
+    }
+    chunk(&mut out, b"IDAT", &zlib_stored(&scanlines));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream holding `data` in uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+    // deflate with a 32K window and no preset dictionary; the check bits make it divide by 31
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+/// The CRC-32 PNG puts after every chunk.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// The Adler-32 checksum closing a zlib stream.
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::Grid,
+        image::{Format, adler32, crc32, zlib_stored},
+        render::Rgb,
+    };
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn stores_blocks() {
+        let data = vec![7; 70_000];
+        let stream = zlib_stored(&data);
+        assert_eq!(stream.len(), 2 + 5 + 65_535 + 5 + 4_465 + 4);
+        assert_eq!(&stream[2..7], &[0, 0xff, 0xff, 0, 0]);
+        assert_eq!(&stream[65_542..65_547], &[1, 0x71, 0x11, 0x8e, 0xee]);
+        assert_eq!(
+            zlib_stored(&[]),
+            vec![0x78, 1, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn encodes() {
+        let grid = Grid::from_cells(vec![true, false], 2);
+        let color = |_, &on: &bool| if on { Rgb::WHITE } else { Rgb(1, 2, 3) };
+        let ppm = Format::Ppm.encode(&grid, 1, color);
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\xff\xff\x01\x02\x03");
+        assert_eq!(
+            &Format::Ppm.encode(&grid, 2, color)[..11],
+            b"P6\n4 2\n255\n"
+        );
+
+        let png = Format::Png.encode(&grid, 1, color);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[8..16], b"\0\0\0\x0dIHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+}
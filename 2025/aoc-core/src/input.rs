@@ -0,0 +1,115 @@
+use crate::Result;
+use std::{
+    env, fmt,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+
+/// Environment variable naming an input file, used when no path is given on the command line.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the input: the command line wins over `AOC_INPUT`, which wins over `default`,
+    /// usually the day's own `data/input.txt`. A path of `-` means stdin.
+    pub fn resolve(cli: Option<&str>, var: Option<String>, default: PathBuf) -> Source {
+        match cli.map(String::from).or(var) {
+            Some(path) if path == "-" => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::File(default),
+        }
+    }
+
+    pub fn from_env(cli: Option<&str>, default: PathBuf) -> Source {
+        Self::resolve(cli, env::var(INPUT_VAR).ok(), default)
+    }
+
+    /// Opens the input for streaming, without reading it into memory.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads an in-memory puzzle input, such as an example from the puzzle text.
+pub fn reader(input: &str) -> impl BufRead + '_ {
+    input.as_bytes()
+}
+
+/// The lines of the input paired with their 1-based line numbers, for error reporting.
+pub fn numbered_lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String)>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line_result)| Ok((i + 1, line_result?)))
+}
+
+/// The 1-based column at which `trimmed`, a subslice of `line`, starts.
+pub fn column_of(line: &str, trimmed: &str) -> usize {
+    let offset = trimmed.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{Source, column_of, numbered_lines, reader};
+    use std::{io::BufRead, path::PathBuf};
+
+    #[test]
+    fn resolves_in_priority_order() {
+        let default = || PathBuf::from("data/input.txt");
+        let var = || Some("from_env.txt".to_string());
+        assert_eq!(
+            Source::resolve(Some("cli.txt"), var(), default()),
+            Source::File(PathBuf::from("cli.txt"))
+        );
+        assert_eq!(
+            Source::resolve(None, var(), default()),
+            Source::File(PathBuf::from("from_env.txt"))
+        );
+        assert_eq!(Source::resolve(Some("-"), var(), default()), Source::Stdin);
+        assert_eq!(
+            Source::resolve(None, None, default()),
+            Source::File(default())
+        );
+    }
+
+    #[test]
+    fn reads_lines() {
+        let lines: Vec<String> = reader("a\nb\n").lines().map(|l| l.expect("")).collect();
+        assert_eq!(lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn numbers_lines_from_one() {
+        let lines: Vec<(usize, String)> = numbered_lines(reader("a\nb"))
+            .map(|l| l.expect(""))
+            .collect();
+        assert_eq!(lines, vec![(1, "a".to_string()), (2, "b".to_string())]);
+    }
+
+    #[test]
+    fn finds_columns() {
+        let line = "  12-34 ";
+        assert_eq!(column_of(line, line.trim()), 3);
+        assert_eq!(column_of(line, &line.trim()[3..]), 6);
+    }
+}
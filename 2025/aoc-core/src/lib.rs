@@ -0,0 +1,56 @@
+//! Shared plumbing for the Advent of Code solvers in this workspace.
+
+use std::{fmt, str::FromStr};
+
+pub mod answers;
+pub mod big;
+pub mod cli;
+pub mod components;
+pub mod digits;
+pub mod error;
+pub mod fixtures;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod neighborhood;
+pub mod path;
+pub mod peel;
+pub mod point;
+pub mod range;
+pub mod render;
+pub mod solution;
+
+pub use error::{AocError, Result};
+pub use solution::Solution;
+
+/// Which half of a day's puzzle to solve.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("part must be 1 or 2, got {other:?}")),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{grid::Grid, point::Point};
+
+/// The offsets that count as adjacent to a cell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Neighborhood {
+    offsets: Vec<Point<i32>>,
+}
+
+impl Neighborhood {
+    /// The four orthogonal neighbors.
+    pub fn von_neumann() -> Self {
+        Self::von_neumann_radius(1)
+    }
+
+    /// The eight orthogonal and diagonal neighbors.
+    pub fn moore() -> Self {
+        Self::moore_radius(1)
+    }
+
+    /// Every cell within Manhattan distance `r`.
+    pub fn von_neumann_radius(r: i32) -> Self {
+        Self::within(r, |y, x| y.abs() + x.abs() <= r)
+    }
+
+    /// Every cell within Chebyshev distance `r`, the `(2r + 1)²` square less its centre.
+    pub fn moore_radius(r: i32) -> Self {
+        Self::within(r, |_, _| true)
+    }
+
+    /// Exactly the given offsets, in that order.
+    pub fn custom(offsets: impl IntoIterator<Item = Point<i32>>) -> Self {
+        Neighborhood {
+            offsets: offsets.into_iter().collect(),
+        }
+    }
+
+    fn within<F: Fn(i32, i32) -> bool>(r: i32, keep: F) -> Self {
+        let offsets = (-r..=r)
+            .flat_map(|y| (-r..=r).map(move |x| Point::new(y, x)))
+            .filter(|&p| p != Point::new(0, 0) && keep(p.y, p.x))
+            .collect();
+        Neighborhood { offsets }
+    }
+
+    pub fn offsets(&self) -> &[Point<i32>] {
+        &self.offsets
+    }
+
+    /// The neighbors of `p`, whether or not they lie on any grid.
+    pub fn around(&self, p: Point<i32>) -> impl Iterator<Item = Point<i32>> + '_ {
+        self.offsets.iter().map(move |&offset| p + offset)
+    }
+
+    /// The cells of `grid` that the neighbors of `p` land on under its edge, which may repeat
+    /// when the grid wraps or clamps.
+    pub fn neighbors<'a, T>(
+        &'a self,
+        grid: &'a Grid<T>,
+        p: Point<i32>,
+    ) -> impl Iterator<Item = Point<i32>> + 'a {
+        self.around(p).filter_map(|n| grid.resolve(n))
+    }
+
+    /// For every cell of `grid`, the cells whose [`neighbors`](Self::neighbors) land on it,
+    /// once for each offset that does. Unless the offsets are symmetric and the grid bounded,
+    /// these are not simply the cell's own neighbors.
+    pub fn counted_by<T>(&self, grid: &Grid<T>) -> Grid<Vec<Point<i32>>> {
+        let mut counted_by = grid.map(|_, _| Vec::new());
+        for p in grid.points() {
+            for n in self.neighbors(grid, p) {
+                counted_by[n].push(p);
+            }
+        }
+        counted_by
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{Edge, Grid},
+        input::reader,
+        neighborhood::Neighborhood,
+        point::Point,
+    };
+
+    #[test]
+    fn sizes() {
+        assert_eq!(Neighborhood::von_neumann().offsets().len(), 4);
+        assert_eq!(Neighborhood::moore().offsets().len(), 8);
+        assert_eq!(Neighborhood::von_neumann_radius(2).offsets().len(), 12);
+        assert_eq!(Neighborhood::moore_radius(2).offsets().len(), 24);
+        let knight = Neighborhood::custom([Point::new(1, 2), Point::new(-2, 1)]);
+        assert_eq!(
+            knight.around(Point::new(5, 5)).collect::<Vec<_>>(),
+            vec![Point::new(6, 7), Point::new(3, 6)]
+        );
+    }
+
+    #[test]
+    fn stays_on_the_grid() {
+        let grid = Grid::parse(reader("...\n..."), Some).expect("");
+        let corner = Point::new(0, 0);
+        assert_eq!(Neighborhood::moore().neighbors(&grid, corner).count(), 3);
+        assert_eq!(
+            Neighborhood::von_neumann().neighbors(&grid, corner).count(),
+            2
+        );
+        assert_eq!(
+            Neighborhood::moore_radius(2)
+                .neighbors(&grid, Point::new(1, 1))
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn finds_who_counts_a_cell() {
+        let grid = Grid::parse(reader("...\n..."), Some).expect("");
+        // only the cell to the left counts one looking right
+        let right = Neighborhood::custom([Point::new(0, 1)]);
+        let counted_by = right.counted_by(&grid);
+        assert_eq!(counted_by[Point::new(1, 2)], vec![Point::new(1, 1)]);
+        assert!(counted_by[Point::new(1, 0)].is_empty());
+        // clamped, the right edge also counts itself
+        let clamped = right.counted_by(&grid.with_edge(Edge::Clamp));
+        assert_eq!(
+            clamped[Point::new(1, 2)],
+            vec![Point::new(1, 1), Point::new(1, 2)]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{grid::Grid, neighborhood::Neighborhood, point::Point};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest route found to a goal: its total cost and every node along it, start and goal
+/// included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// The nodes reached so far, each with the node it was reached from and its best cost.
+struct Visited<N, C> {
+    index: HashMap<N, usize>,
+    nodes: Vec<(N, Option<usize>, C)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Visited {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![(start, None, zero)],
+        }
+    }
+
+    /// Records reaching `node` from the node at `from` for `cost`, unless `better` rejects
+    /// that over what was recorded before. Returns where it is recorded when it was kept.
+    fn reach<F>(&mut self, node: N, from: usize, cost: C, better: F) -> Option<usize>
+    where
+        F: Fn(C, C) -> bool,
+    {
+        match self.index.get(&node) {
+            Some(&i) if !better(cost, self.nodes[i].2) => None,
+            Some(&i) => {
+                self.nodes[i].1 = Some(from);
+                self.nodes[i].2 = cost;
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(node.clone(), i);
+                self.nodes.push((node, Some(from), cost));
+                Some(i)
+            }
+        }
+    }
+
+    fn path_to(&self, mut i: usize) -> Path<N, C> {
+        let cost = self.nodes[i].2;
+        let mut nodes = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// The route with the fewest steps from `start` to the first node `is_goal` accepts.
+pub fn bfs<N, I, FN, FG>(start: N, mut neighbors: FN, mut is_goal: FG) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FG: FnMut(&N) -> bool,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(visited.path_to(i));
+        }
+        for next in neighbors(&node) {
+            // the first visit to a node is always by the fewest steps
+            if let Some(j) = visited.reach(next, i, steps + 1, |_, _| false) {
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest route from `start` to the first node `is_goal` accepts, where stepping from a
+/// node to one of its neighbors costs `cost`. Costs must not be negative.
+pub fn dijkstra<N, C, I, FN, FC, FG>(
+    start: N,
+    neighbors: FN,
+    cost: FC,
+    is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FC: FnMut(&N, &N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, cost, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but searching first where `heuristic` expects the goal to be closest. The
+/// route is the cheapest as long as `heuristic` never overestimates the remaining cost.
+pub fn astar<N, C, I, FN, FC, FH, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut cost: FC,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FC: FnMut(&N, &N) -> C,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut open = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Reverse((_, spent, i))) = open.pop() {
+        let (node, _, best) = visited.nodes[i].clone();
+        if spent > best {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(visited.path_to(i));
+        }
+        for next in neighbors(&node) {
+            let total = spent + cost(&node, &next);
+            let estimate = total + heuristic(&next);
+            if let Some(j) = visited.reach(next, i, total, |new, old| new < old) {
+                open.push(Reverse((estimate, total, j)));
+            }
+        }
+    }
+    None
+}
+
+/// A neighbor function walking `grid` through `neighborhood` onto the cells `open` allows.
+pub fn grid_steps<'a, T, F>(
+    grid: &'a Grid<T>,
+    neighborhood: &'a Neighborhood,
+    open: F,
+) -> impl Fn(&Point<i32>) -> Vec<Point<i32>> + 'a
+where
+    F: Fn(&T) -> bool + 'a,
+{
+    move |&p| {
+        neighborhood
+            .neighbors(grid, p)
+            .filter(|&n| open(&grid[n]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::Grid,
+        input::reader,
+        neighborhood::Neighborhood,
+        path::{astar, bfs, dijkstra, grid_steps},
+        point::Point,
+    };
+
+    const MAZE: &str = "\
+        11111
+        1.1.1
+        11911
+        ...11";
+
+    #[test]
+    fn walks_grids() {
+        let grid = Grid::parse(reader(MAZE), |c| Some(c.to_digit(10))).expect("");
+        let hood = Neighborhood::von_neumann();
+        let steps = grid_steps(&grid, &hood, |cell: &Option<u32>| cell.is_some());
+        let (start, goal) = (Point::new(0, 0), Point::new(2, 3));
+
+        // straight through the 9, or the long way round the top
+        let fewest = bfs(start, &steps, |&p| p == goal).expect("");
+        assert_eq!(fewest.cost, 5);
+        assert_eq!(fewest.nodes.len(), 6);
+        assert_eq!((fewest.nodes[0], fewest.nodes[5]), (start, goal));
+        assert!(fewest.nodes.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let cost = |_: &Point<i32>, to: &Point<i32>| grid[*to].expect("steps stay open");
+        let cheapest = dijkstra(start, &steps, cost, |&p| p == goal).expect("");
+        assert_eq!(cheapest.cost, 7);
+        assert_eq!(cheapest.nodes.len(), 8);
+        let guided = astar(
+            start,
+            &steps,
+            cost,
+            |p| p.manhattan(goal) as u32,
+            |&p| p == goal,
+        );
+        assert_eq!(guided.map(|path| path.cost), Some(7));
+
+        assert!(bfs(start, &steps, |&p| p == Point::new(1, 1)).is_none());
+    }
+
+    #[test]
+    fn walks_graphs() {
+        let moves = |&n: &u32| [n + 1, n * 2];
+        let path = bfs(1, moves, |&n| n == 10).expect("");
+        assert_eq!((path.cost, path.nodes), (4, vec![1, 2, 4, 5, 10]));
+        // doubling is cheaper than adding one
+        let cost = |&from: &u32, &to: &u32| if to == from * 2 { 1 } else { 2 };
+        let path = dijkstra(1, moves, cost, |&n| n == 10).expect("");
+        assert_eq!((path.cost, path.nodes), (5, vec![1, 2, 4, 5, 10]));
+        let path = dijkstra(1, moves, cost, |&n| n == 9).expect("");
+        assert_eq!((path.cost, path.nodes), (5, vec![1, 2, 4, 8, 9]));
+        let start = dijkstra(1, moves, cost, |&n| n == 1).expect("");
+        assert_eq!((start.cost, start.nodes), (0, vec![1]));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{grid::Grid, neighborhood::Neighborhood};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Every member's core number: the largest `k` for which it survives repeatedly removing the
+/// members with fewer than `k` member neighbors. Cells that are not members get `None`.
+///
+/// So removing everything with fewer than `k` neighbors, for as long as there is any, removes
+/// exactly the members whose core number is below `k`. Members past the edge of a grid with a
+/// fixed outside value are never removed.
+pub fn core_numbers<T, F>(
+    grid: &Grid<T>,
+    neighborhood: &Neighborhood,
+    member: F,
+) -> Grid<Option<usize>>
+where
+    F: Fn(&T) -> bool,
+{
+    let mut degrees = grid.map(|p, cell| {
+        member(cell).then(|| {
+            neighborhood
+                .around(p)
+                .filter(|&n| grid.get(n).is_some_and(&member))
+                .count()
+        })
+    });
+    // peeling a member lowers the degree of every member that counted it, which need not be
+    // its own neighbors when the neighborhood is lopsided or the grid wraps or clamps
+    let counted_by = neighborhood.counted_by(grid);
+    let mut cores = grid.map(|_, _| None);
+    let mut queue: BinaryHeap<_> = degrees
+        .iter()
+        .filter_map(|(p, degree)| degree.map(|d| Reverse((d, p))))
+        .collect();
+    let mut core = 0;
+    while let Some(Reverse((degree, p))) = queue.pop() {
+        if cores[p].is_some() || degrees[p] != Some(degree) {
+            // already peeled, or queued again since with fewer neighbors
+            continue;
+        }
+        core = core.max(degree);
+        cores[p] = Some(core);
+        for &n in &counted_by[p] {
+            if cores[n].is_none()
+                && let Some(d) = degrees[n].as_mut()
+            {
+                *d -= 1;
+                queue.push(Reverse((*d, n)));
+            }
+        }
+    }
+    cores
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{Edge, Grid},
+        input::reader,
+        neighborhood::Neighborhood,
+        peel::core_numbers,
+        point::Point,
+    };
+
+    const FLOOR: &str = "\
+        ..@@.@@@@.
+        @@@.@.@.@@
+        @@@@@.@.@@
+        @.@@@@..@.
+        @@.@@@@.@@
+        .@@@@@@@.@
+        .@.@.@.@@@
+        @.@@@.@@@@
+        .@@@@@@@@.
+        @.@.@@@.@.";
+
+    /// Removes members with fewer than `k` member neighbors a round at a time until none are
+    /// left to remove, returning how many went.
+    fn cascade(grid: &Grid<bool>, neighborhood: &Neighborhood, k: usize) -> usize {
+        let mut grid = grid.clone();
+        let mut removed = 0;
+        loop {
+            let doomed: Vec<Point<i32>> = grid
+                .iter()
+                .filter(|(p, on)| {
+                    **on && neighborhood
+                        .around(*p)
+                        .filter(|&n| grid.get(n) == Some(&true))
+                        .count()
+                        < k
+                })
+                .map(|(p, _)| p)
+                .collect();
+            if doomed.is_empty() {
+                return removed;
+            }
+            removed += doomed.len();
+            for p in doomed {
+                grid[p] = false;
+            }
+        }
+    }
+
+    #[test]
+    fn peels_a_block() {
+        let block = Grid::parse(reader("@@@\n@@@\n@@@"), |c| Some(c == '@')).expect("");
+        let cores = core_numbers(&block, &Neighborhood::moore(), |&on| on);
+        assert!(cores.iter().all(|(_, core)| *core == Some(3)));
+        let walled = block.with_edge(Edge::Outside(true));
+        let cores = core_numbers(&walled, &Neighborhood::moore(), |&on| on);
+        assert!(cores.iter().all(|(_, core)| *core == Some(8)));
+    }
+
+    #[test]
+    fn matches_the_cascade_for_every_threshold() {
+        let lopsided = Neighborhood::custom(
+            [(0, 1), (0, 2), (1, 0), (1, 1), (0, 3)].map(|(y, x)| Point::new(y, x)),
+        );
+        let block = Grid::parse(reader("@@@@@\n@@@@@\n@@.@@\n@@@@@"), |c| Some(c == '@'));
+        let block = block.expect("");
+        let cores = core_numbers(&block, &lopsided, |&on| on);
+        for k in 1..=4 {
+            let below = cores
+                .iter()
+                .filter(|(_, c)| c.is_some_and(|c| c < k))
+                .count();
+            assert_eq!((below, cascade(&block, &lopsided, k)), (19, 19), "k = {k}");
+        }
+
+        let floor = Grid::parse(reader(FLOOR), |c| Some(c == '@')).expect("");
+        for (neighborhood, edge) in [
+            (Neighborhood::moore(), Edge::Bounded),
+            (lopsided.clone(), Edge::Bounded),
+            (lopsided, Edge::Clamp),
+            (Neighborhood::von_neumann(), Edge::Bounded),
+            (Neighborhood::moore(), Edge::Wrap),
+            (Neighborhood::moore_radius(2), Edge::Outside(true)),
+        ] {
+            let floor = floor.clone().with_edge(edge);
+            let cores = core_numbers(&floor, &neighborhood, |&on| on);
+            assert_eq!(cores[Point::new(0, 0)], None);
+            for k in 0..=neighborhood.offsets().len() + 1 {
+                let below = cores
+                    .iter()
+                    .filter(|(_, c)| c.is_some_and(|c| c < k))
+                    .count();
+                assert_eq!(below, cascade(&floor, &neighborhood, k), "k = {k}");
+            }
+        }
+    }
+}
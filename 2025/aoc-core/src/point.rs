@@ -0,0 +1,238 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a grid, row first, with rows growing downwards.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T> {
+    pub y: T,
+    pub x: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(y: T, x: T) -> Self {
+        Point { y, x }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// Steps between the points moving only along rows and columns.
+    pub fn manhattan(self, other: Point<T>) -> T {
+        distance(self.y, other.y) + distance(self.x, other.x)
+    }
+
+    /// Steps between the points when diagonal moves are allowed too.
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        distance(self.y, other.y).max(distance(self.x, other.x))
+    }
+}
+
+/// `|a - b|`, without going negative for unsigned types.
+fn distance<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    a.max(b) - a.min(b)
+}
+
+impl<T: Copy + Neg<Output = T>> Point<T> {
+    /// Turns the offset a quarter clockwise as drawn, so north becomes east.
+    pub fn rotate_right(self) -> Point<T> {
+        Point::new(self.x, -self.y)
+    }
+
+    /// Turns the offset a quarter anticlockwise as drawn, so north becomes west.
+    pub fn rotate_left(self) -> Point<T> {
+        Point::new(-self.x, self.y)
+    }
+}
+
+impl<T: Copy + TryFrom<usize> + TryInto<usize>> Point<T> {
+    /// The point at `index` in a row-major layout `width` cells wide, if it fits in `T`.
+    pub fn from_index(index: usize, width: usize) -> Option<Point<T>> {
+        let y = index.checked_div(width)?;
+        Some(Point::new(
+            T::try_from(y).ok()?,
+            T::try_from(index % width).ok()?,
+        ))
+    }
+
+    /// The inverse of [`Point::from_index`]: `None` for negative coordinates or a column
+    /// outside `width`.
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let y: usize = self.y.try_into().ok()?;
+        let x: usize = self.x.try_into().ok().filter(|&x| x < width)?;
+        y.checked_mul(width)?.checked_add(x)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    #[inline]
+    fn add(self, rhs: Point<T>) -> Point<T> {
+        Point {
+            y: self.y + rhs.y,
+            x: self.x + rhs.x,
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    #[inline]
+    fn sub(self, rhs: Point<T>) -> Point<T> {
+        Point {
+            y: self.y - rhs.y,
+            x: self.x - rhs.x,
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    #[inline]
+    fn neg(self) -> Point<T> {
+        Point {
+            y: -self.y,
+            x: -self.x,
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    #[inline]
+    fn mul(self, scale: T) -> Point<T> {
+        Point {
+            y: self.y * scale,
+            x: self.x * scale,
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Point<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Point<T>) {
+        *self = *self - rhs;
+    }
+}
+
+/// A compass direction on a grid drawn with north at the top, numbered clockwise from north
+/// like [`Direction::ALL`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    N = 0,
+    NE = 1,
+    E = 2,
+    SE = 3,
+    S = 4,
+    SW = 5,
+    W = 6,
+    NW = 7,
+}
+
+impl Direction {
+    /// North, east, south and west, clockwise.
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// The step one cell this way.
+    pub const fn offset(self) -> Point<i32> {
+        let (y, x) = match self {
+            Direction::N => (-1, 0),
+            Direction::NE => (-1, 1),
+            Direction::E => (0, 1),
+            Direction::SE => (1, 1),
+            Direction::S => (1, 0),
+            Direction::SW => (1, -1),
+            Direction::W => (0, -1),
+            Direction::NW => (-1, -1),
+        };
+        Point::new(y, x)
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    /// The direction `eighths` of a full turn clockwise.
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::{Direction, Point};
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Point::new(1, -2), Point::new(3, 4));
+        assert_eq!(a + b, Point::new(4, 2));
+        assert_eq!(a - b, Point::new(-2, -6));
+        assert_eq!(-a, Point::new(-1, 2));
+        assert_eq!(b * 3, Point::new(9, 12));
+        let mut c = a;
+        c += b;
+        c -= Point::new(1, 1);
+        assert_eq!(c, Point::new(3, 1));
+    }
+
+    #[test]
+    fn metrics() {
+        let (a, b) = (Point::new(1, -2), Point::new(3, 4));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (8, 6));
+        assert_eq!(Point::new(5u32, 1).manhattan(Point::new(2, 3)), 5);
+    }
+
+    #[test]
+    fn rotates_with_directions() {
+        for d in Direction::ALL {
+            assert_eq!(d.offset().rotate_right(), d.turn_right().offset());
+            assert_eq!(d.offset().rotate_left(), d.turn_left().offset());
+            assert_eq!(-d.offset(), d.opposite().offset());
+        }
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::NW.turn_left(), Direction::SW);
+        for (i, d) in Direction::ALL.into_iter().enumerate() {
+            assert_eq!(d as usize, i);
+        }
+    }
+
+    #[test]
+    fn converts_indices() {
+        assert_eq!(Point::<i32>::from_index(7, 3), Some(Point::new(2, 1)));
+        assert_eq!(Point::<i32>::from_index(7, 0), None);
+        assert_eq!(Point::<u8>::from_index(3000, 3), None);
+        assert_eq!(Point::new(2, 1).to_index(3), Some(7));
+        assert_eq!(Point::new(2, 3).to_index(3), None);
+        assert_eq!(Point::new(-1, 1).to_index(3), None);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{AocError, Result};
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// Parses an inclusive `low-high` range such as `11-22`, rejecting one whose `low` is above its
+/// `high`. Errors point into `input`, so use
+/// [`AocError::shifted`] to place them in the wider puzzle input.
+pub fn parse_range<T>(input: &str) -> Result<RangeInclusive<T>>
+where
+    T: FromStr + PartialOrd,
+    T::Err: Display,
+{
+    let (low, high) = input
+        .split_once('-')
+        .ok_or_else(|| AocError::parse(1, 1, input, "expected a range like 1-2"))?;
+    let parse =
+        |s: &str, column: usize| s.parse::<T>().map_err(|e| AocError::parse(1, column, s, e));
+    let (low, high) = (parse(low, 1)?, parse(high, low.chars().count() + 2)?);
+    if low > high {
+        return Err(AocError::parse(1, 1, input, "range starts after it ends"));
+    }
+    Ok(low..=high)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::range::parse_range;
+
+    #[test]
+    fn parses() {
+        assert_eq!(parse_range::<i64>("11-22").expect(""), 11..=22);
+        assert_eq!(parse_range::<u64>("3-3").expect(""), 3..=3);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let err = |s| parse_range::<i64>(s).expect_err("").position();
+        assert_eq!(err("11"), Some((1, 1)));
+        assert_eq!(err("a-2"), Some((1, 1)));
+        assert_eq!(err("10-2x"), Some((1, 4)));
+        assert_eq!(err("10-3"), Some((1, 1)));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{grid::Grid, point::Point};
+use std::{
+    env,
+    fmt::Write as _,
+    io::{self, IsTerminal, Write as _},
+};
+
+/// A 24-bit colour.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const CYAN: Rgb = Rgb(42, 161, 152);
+
+    /// The colour `t` of the way from `self` to `to`, with `t` held within 0 to 1.
+    pub fn lerp(self, to: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(mix(self.0, to.0), mix(self.1, to.1), mix(self.2, to.2))
+    }
+}
+
+/// What an overlay changes about a cell. Anything left unset shows what is underneath.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Style {
+    pub glyph: Option<char>,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl Style {
+    pub fn glyph(glyph: char) -> Style {
+        Style {
+            glyph: Some(glyph),
+            ..Style::default()
+        }
+    }
+
+    pub fn fg(fg: Rgb) -> Style {
+        Style {
+            fg: Some(fg),
+            ..Style::default()
+        }
+    }
+
+    pub fn bg(bg: Rgb) -> Style {
+        Style {
+            bg: Some(bg),
+            ..Style::default()
+        }
+    }
+
+    /// This style laid on top of `below`.
+    pub fn over(self, below: Style) -> Style {
+        Style {
+            glyph: self.glyph.or(below.glyph),
+            fg: self.fg.or(below.fg),
+            bg: self.bg.or(below.bg),
+        }
+    }
+}
+
+/// Styles some of a grid's cells, returning `None` for cells it leaves alone.
+pub type Overlay<'a> = &'a dyn Fn(Point<i32>) -> Option<Style>;
+
+/// Draws `grid` one line per row, each cell as `glyph` makes it, with `overlays` laid on top
+/// in order. Without `color` only the overlays' glyphs show.
+pub fn render<T, F>(grid: &Grid<T>, glyph: F, overlays: &[Overlay], color: bool) -> String
+where
+    F: Fn(&T) -> char,
+{
+    let mut out = String::new();
+    for (p, cell) in grid.iter() {
+        let style = overlays
+            .iter()
+            .filter_map(|overlay| overlay(p))
+            .fold(Style::default(), |below, above| above.over(below));
+        let glyph = style.glyph.unwrap_or_else(|| glyph(cell));
+        if color && (style.fg.is_some() || style.bg.is_some()) {
+            if let Some(Rgb(r, g, b)) = style.fg {
+                write!(out, "\x1b[38;2;{r};{g};{b}m").expect("writing to a String");
+            }
+            if let Some(Rgb(r, g, b)) = style.bg {
+                write!(out, "\x1b[48;2;{r};{g};{b}m").expect("writing to a String");
+            }
+            out.push(glyph);
+            out.push_str("\x1b[0m");
+        } else {
+            out.push(glyph);
+        }
+        if p.x as usize + 1 == grid.width() {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Whether stdout takes colour: it is a terminal and `NO_COLOR` is not set.
+pub fn stdout_has_color() -> bool {
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none()
+}
+
+/// Prints `grid` like [`render`], in colour only when stdout is a terminal.
+pub fn print<T, F>(grid: &Grid<T>, glyph: F, overlays: &[Overlay]) -> io::Result<()>
+where
+    F: Fn(&T) -> char,
+{
+    let text = render(grid, glyph, overlays, stdout_has_color());
+    io::stdout().lock().write_all(text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::Grid,
+        input::reader,
+        point::Point,
+        render::{Rgb, Style, render},
+    };
+
+    #[test]
+    fn overlays_in_order() {
+        let grid = Grid::parse(reader("ab\ncd"), Some).expect("");
+        let corner = |p: Point<i32>| (p == Point::new(0, 0)).then_some(Style::fg(Rgb::RED));
+        let top = |p: Point<i32>| {
+            (p.y == 0).then_some(Style {
+                glyph: Some('*'),
+                bg: Some(Rgb::BLUE),
+                ..Style::default()
+            })
+        };
+        let glyph = |c: &char| c.to_ascii_uppercase();
+        assert_eq!(render(&grid, glyph, &[], true), "AB\nCD\n");
+        assert_eq!(render(&grid, glyph, &[&corner, &top], false), "**\nCD\n");
+        assert_eq!(
+            render(&grid, glyph, &[&corner, &top], true),
+            "\x1b[38;2;220;50;47m\x1b[48;2;38;139;210m*\x1b[0m\
+             \x1b[48;2;38;139;210m*\x1b[0m\nCD\n"
+        );
+    }
+
+    #[test]
+    fn blends() {
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::BLACK.lerp(Rgb::RED, 2.0), Rgb::RED);
+    }
+}
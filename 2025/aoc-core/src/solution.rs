@@ -0,0 +1,81 @@
+use crate::{Part, Result};
+use std::{fmt::Display, io::BufRead};
+
+/// A day's puzzle: parse the input once, then answer either part from the parsed form.
+pub trait Solution {
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Self::Part1;
+
+    fn part2(parsed: &Self::Parsed) -> Self::Part2;
+}
+
+/// Renders the answer to one part of an already parsed input.
+pub fn answer<S: Solution>(parsed: &S::Parsed, part: Part) -> String {
+    match part {
+        Part::One => S::part1(parsed).to_string(),
+        Part::Two => S::part2(parsed).to_string(),
+    }
+}
+
+/// Parses `reader` once and answers each of `parts` in order.
+pub fn solve<S: Solution>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<String>> {
+    let parsed = S::parse(reader)?;
+    Ok(parts
+        .iter()
+        .map(|&part| answer::<S>(&parsed, part))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AocError, Part, Result,
+        input::{numbered_lines, reader},
+        solution::{Solution, solve},
+    };
+    use std::io::BufRead;
+
+    /// Sums the numbers on each line, or multiplies them for part 2.
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = Vec<u64>;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn parse(reader: impl BufRead) -> Result<Vec<u64>> {
+            numbered_lines(reader)
+                .map(|numbered| {
+                    let (line_no, line) = numbered?;
+                    line.parse()
+                        .map_err(|e| AocError::parse(line_no, 1, &line, e))
+                })
+                .collect()
+        }
+
+        fn part1(nums: &Vec<u64>) -> u64 {
+            nums.iter().sum()
+        }
+
+        fn part2(nums: &Vec<u64>) -> u64 {
+            nums.iter().product()
+        }
+    }
+
+    #[test]
+    fn solves_requested_parts() {
+        let ans = solve::<Lines>(&mut reader("2\n3\n4"), &[Part::Two, Part::One]).expect("");
+        assert_eq!(ans, vec!["24", "9"]);
+    }
+
+    #[test]
+    fn surfaces_parse_errors() {
+        let err = solve::<Lines>(&mut reader("2\nx"), &Part::BOTH).expect_err("");
+        assert_eq!(err.position(), Some((2, 1)));
+    }
+}
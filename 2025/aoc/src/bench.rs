@@ -0,0 +1,182 @@
+use crate::input::workspace_root;
+use aoc_core::{Result, Solution};
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Once a phase has used this much time it stops early, so slow days stay bearable.
+const BUDGET: Duration = Duration::from_secs(3);
+
+/// A phase over budget still runs this many times, unless fewer runs were asked for.
+const MIN_RUNS: usize = 5;
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// The timing spread of one phase.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Summary {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Summary {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort();
+        let at = |q: f64| samples[((samples.len() - 1) as f64 * q).round() as usize];
+        Summary {
+            runs: samples.len(),
+            min: samples[0],
+            median: at(0.5),
+            p95: at(0.95),
+        }
+    }
+}
+
+/// Times `f` up to `runs` times.
+fn sample<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<Summary> {
+    let mut samples = Vec::with_capacity(runs);
+    let mut spent = Duration::ZERO;
+    for i in 0..runs.max(1) {
+        let start = Instant::now();
+        std::hint::black_box(f()?);
+        let took = start.elapsed();
+        samples.push(took);
+        spent += took;
+        if i + 1 >= MIN_RUNS && spent >= BUDGET {
+            break;
+        }
+    }
+    Ok(Summary::from_samples(samples))
+}
+
+/// Times parsing and each part of `S` separately, in the order of [`PHASES`].
+pub fn phases<S: Solution>(input: &[u8], runs: usize) -> Result<[Summary; 3]> {
+    let parse = sample(runs, || S::parse(input))?;
+    let parsed = S::parse(input)?;
+    let part1 = sample(runs, || Ok(S::part1(&parsed)))?;
+    let part2 = sample(runs, || Ok(S::part2(&parsed)))?;
+    Ok([parse, part1, part2])
+}
+
+/// Median times from an earlier run, keyed by (year, day, phase).
+pub type Baseline = BTreeMap<(u32, u32, String), Duration>;
+
+pub fn default_baseline_path() -> PathBuf {
+    workspace_root().join("bench-baseline.txt")
+}
+
+/// Reads a baseline of `year day phase median_ns` lines. A missing file is an empty baseline.
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Baseline::new()),
+        Err(e) => return Err(e),
+    };
+    let mut baseline = Baseline::new();
+    for (i, line) in text.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{}:{}: expected `year day phase nanos`",
+                    path.display(),
+                    i + 1
+                ),
+            )
+        };
+        let [year, day, phase, nanos] = fields.as_slice() else {
+            if fields.is_empty() {
+                continue;
+            }
+            return Err(invalid());
+        };
+        let year = year.parse().map_err(|_| invalid())?;
+        let day = day.parse().map_err(|_| invalid())?;
+        let nanos = nanos.parse().map_err(|_| invalid())?;
+        baseline.insert((year, day, phase.to_string()), Duration::from_nanos(nanos));
+    }
+    Ok(baseline)
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let text: String = baseline
+        .iter()
+        .map(|((year, day, phase), median)| format!("{year} {day} {phase} {}\n", median.as_nanos()))
+        .collect();
+    fs::write(path, text)
+}
+
+/// How `now` compares with `then`, as a signed percentage.
+pub fn change(then: Duration, now: Duration) -> String {
+    let pct = (now.as_secs_f64() / then.as_secs_f64() - 1.0) * 100.0;
+    format!("{pct:+.1}%")
+}
+
+/// Formats a duration with a unit that keeps about three significant digits.
+pub struct Short(pub Duration);
+
+impl fmt::Display for Short {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let text = if nanos < 1e3 {
+            format!("{nanos}ns")
+        } else if nanos < 1e6 {
+            format!("{:.1}µs", nanos / 1e3)
+        } else if nanos < 1e9 {
+            format!("{:.2}ms", nanos / 1e6)
+        } else {
+            format!("{:.2}s", nanos / 1e9)
+        };
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{Baseline, Short, Summary, change, load_baseline, save_baseline};
+    use std::{env, time::Duration};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn summarizes() {
+        let s = Summary::from_samples((1..=20).rev().map(ms).collect());
+        assert_eq!(
+            (s.runs, s.min, s.median, s.p95),
+            (20, ms(1), ms(11), ms(19))
+        );
+        let s = Summary::from_samples(vec![ms(4)]);
+        assert_eq!((s.min, s.median, s.p95), (ms(4), ms(4), ms(4)));
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Short(Duration::from_nanos(812)).to_string(), "812ns");
+        assert_eq!(Short(Duration::from_nanos(12_340)).to_string(), "12.3µs");
+        assert_eq!(Short(Duration::from_micros(1_310)).to_string(), "1.31ms");
+        assert_eq!(Short(ms(2_040)).to_string(), "2.04s");
+        assert_eq!(change(ms(100), ms(110)), "+10.0%");
+        assert_eq!(change(ms(100), ms(95)), "-5.0%");
+    }
+
+    #[test]
+    fn round_trips_baseline() {
+        let path = env::temp_dir().join(format!("aoc-baseline-{}.txt", std::process::id()));
+        let mut baseline = Baseline::new();
+        baseline.insert((2025, 4, "parse".to_string()), ms(3));
+        baseline.insert((2025, 4, "part2".to_string()), Duration::from_nanos(17));
+        save_baseline(&path, &baseline).expect("");
+        assert_eq!(load_baseline(&path).expect(""), baseline);
+        std::fs::remove_file(&path).expect("");
+        assert!(load_baseline(&path).expect("").is_empty());
+    }
+}
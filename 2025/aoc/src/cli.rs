@@ -0,0 +1,260 @@
+use aoc_core::Part;
+
+pub const USAGE: &str = "\
+usage: aoc <year> <day> [--part <1|2>] [--input <path|->]
+       aoc verify [<year> [<day>]]
+       aoc bench [<year> [<day>]] [--runs <n>] [--save] [--baseline <path>]
+       aoc new <year> <day>
+
+Runs the registered solver for the given day, printing both parts unless one is picked.
+Input is read from --input, then $AOC_INPUT, then the day's data/input.txt; `-` is stdin.
+
+verify reruns every registered day, or just those matching the year and day, on its
+data/input.txt and compares the answers with data/answers.toml.
+
+bench times parsing and each part separately, reporting min, median and p95, and compares
+medians with the saved baseline (bench-baseline.txt in the workspace). --save replaces the
+baseline entries for the days just run.
+
+new creates a day<N> crate with a solver skeleton and a placeholder example whose test fails
+until it is filled in, then adds it to the workspace and the runner's registry.";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run {
+        year: u32,
+        day: u32,
+        part: Option<Part>,
+        input: Option<String>,
+    },
+    Verify {
+        year: Option<u32>,
+        day: Option<u32>,
+    },
+    Bench {
+        year: Option<u32>,
+        day: Option<u32>,
+        runs: usize,
+        save: bool,
+        baseline: Option<String>,
+    },
+    New {
+        year: u32,
+        day: u32,
+    },
+}
+
+pub const DEFAULT_RUNS: usize = 100;
+
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut input = None;
+    let mut runs = None;
+    let mut save = false;
+    let mut baseline = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                let value = args.next().ok_or("--part needs a value")?;
+                part = Some(value.parse()?);
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().ok_or("--input needs a path")?);
+            }
+            "--runs" => {
+                let value = args.next().ok_or("--runs needs a count")?;
+                runs = Some(number("runs", &value)? as usize);
+            }
+            "--save" => save = true,
+            "--baseline" => baseline = Some(args.next().ok_or("--baseline needs a path")?),
+            "-" => positional.push(arg),
+            flag if flag.starts_with('-') => return Err(format!("unknown flag {flag}")),
+            _ => positional.push(arg),
+        }
+    }
+    let subcommand = positional.first().map(String::as_str);
+    let bench_flags = runs.is_some() || save || baseline.is_some();
+    if subcommand == Some("verify") {
+        if part.is_some() || input.is_some() || bench_flags {
+            return Err("verify takes only a year and a day".to_string());
+        }
+        let (year, day) = filter("verify", &positional[1..])?;
+        return Ok(Command::Verify { year, day });
+    }
+    if subcommand == Some("bench") {
+        if part.is_some() || input.is_some() {
+            return Err("bench takes no --part or --input".to_string());
+        }
+        let (year, day) = filter("bench", &positional[1..])?;
+        return Ok(Command::Bench {
+            year,
+            day,
+            runs: runs.unwrap_or(DEFAULT_RUNS),
+            save,
+            baseline,
+        });
+    }
+    if subcommand == Some("new") {
+        if part.is_some() || input.is_some() || bench_flags {
+            return Err("new takes only a year and a day".to_string());
+        }
+        let [_, year, day] = positional.as_slice() else {
+            return Err("new needs a year and a day".to_string());
+        };
+        return Ok(Command::New {
+            year: number("year", year)?,
+            day: number("day", day)?,
+        });
+    }
+    if bench_flags {
+        return Err("--runs, --save and --baseline only apply to bench".to_string());
+    }
+    let (year, day) = match positional.as_slice() {
+        [year, day] => (year, day),
+        [year, day, path] => {
+            input = Some(path.clone());
+            (year, day)
+        }
+        _ => return Err("expected a year and a day".to_string()),
+    };
+    Ok(Command::Run {
+        year: number("year", year)?,
+        day: number("day", day)?,
+        part,
+        input,
+    })
+}
+
+/// The optional year and day narrowing a subcommand to some of the registered days.
+fn filter(command: &str, args: &[String]) -> Result<(Option<u32>, Option<u32>), String> {
+    match args {
+        [] => Ok((None, None)),
+        [year] => Ok((Some(number("year", year)?), None)),
+        [year, day] => Ok((Some(number("year", year)?), Some(number("day", day)?))),
+        _ => Err(format!("{command} takes at most a year and a day")),
+    }
+}
+
+fn number(name: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{name} must be a number, got {value:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Command, DEFAULT_RUNS, parse};
+    use aoc_core::Part;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_run() {
+        assert_eq!(
+            parse(args("2025 4 --part 1")),
+            Ok(Command::Run {
+                year: 2025,
+                day: 4,
+                part: Some(Part::One),
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse(args("2025 7")),
+            Ok(Command::Run {
+                year: 2025,
+                day: 7,
+                part: None,
+                input: None,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_input() {
+        let expected = |input: &str| {
+            Ok(Command::Run {
+                year: 2025,
+                day: 1,
+                part: None,
+                input: Some(input.to_string()),
+            })
+        };
+        assert_eq!(parse(args("2025 1 --input big.txt")), expected("big.txt"));
+        assert_eq!(parse(args("2025 1 big.txt")), expected("big.txt"));
+        assert_eq!(parse(args("2025 1 -")), expected("-"));
+    }
+
+    #[test]
+    fn parses_verify() {
+        assert_eq!(
+            parse(args("verify")),
+            Ok(Command::Verify {
+                year: None,
+                day: None
+            })
+        );
+        assert_eq!(
+            parse(args("verify 2025 3")),
+            Ok(Command::Verify {
+                year: Some(2025),
+                day: Some(3)
+            })
+        );
+        assert!(parse(args("verify 2025 3 1")).is_err());
+        assert!(parse(args("verify --part 1")).is_err());
+    }
+
+    #[test]
+    fn parses_bench() {
+        assert_eq!(
+            parse(args("bench 2025 --runs 7 --save")),
+            Ok(Command::Bench {
+                year: Some(2025),
+                day: None,
+                runs: 7,
+                save: true,
+                baseline: None,
+            })
+        );
+        assert_eq!(
+            parse(args("bench --baseline old.txt")),
+            Ok(Command::Bench {
+                year: None,
+                day: None,
+                runs: DEFAULT_RUNS,
+                save: false,
+                baseline: Some("old.txt".to_string()),
+            })
+        );
+        assert!(parse(args("bench --part 1")).is_err());
+        assert!(parse(args("2025 4 --save")).is_err());
+    }
+
+    #[test]
+    fn parses_new() {
+        assert_eq!(
+            parse(args("new 2025 8")),
+            Ok(Command::New { year: 2025, day: 8 })
+        );
+        assert!(parse(args("new 2025")).is_err());
+        assert!(parse(args("new 2025 8 --part 1")).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_args() {
+        assert!(parse(args("2025")).is_err());
+        assert!(parse(args("2025 x")).is_err());
+        assert!(parse(args("2025 4 --part 3")).is_err());
+        assert!(parse(args("2025 4 --bogus")).is_err());
+        assert!(parse(args("2025 4 --input")).is_err());
+        assert!(parse(args("2025 4 a.txt b.txt")).is_err());
+    }
+}
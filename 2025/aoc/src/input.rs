@@ -0,0 +1,57 @@
+use crate::registry::Day;
+use aoc_core::input::Source;
+use std::{
+    io::BufRead,
+    path::{Path, PathBuf},
+};
+
+/// Opens `source` as `day`'s input, naming the day when it cannot be read.
+pub fn open(source: &Source, day: &Day) -> Result<Box<dyn BufRead>, String> {
+    source
+        .open()
+        .map_err(|e| format!("{}: cannot read input {source}: {e}", day_label(day)))
+}
+
+/// The workspace directory holding every day crate.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner lives inside the workspace")
+}
+
+pub fn default_path(day: &Day) -> PathBuf {
+    workspace_root().join(day.name).join("data/input.txt")
+}
+
+pub fn day_label(day: &Day) -> String {
+    format!("{} day {} ({})", day.year, day.day, day.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        input::{default_path, open},
+        registry::find,
+    };
+    use aoc_core::input::Source;
+    use std::path::PathBuf;
+
+    #[test]
+    fn defaults_to_the_days_input() {
+        let day = find(2025, 4).expect("");
+        assert_eq!(
+            Source::from_env(Some("-"), default_path(day)),
+            Source::Stdin
+        );
+        assert!(default_path(day).ends_with("day4/data/input.txt"));
+    }
+
+    #[test]
+    fn missing_file_names_the_day() {
+        let day = find(2025, 5).expect("");
+        let Err(err) = open(&Source::File(PathBuf::from("/nonexistent/input.txt")), day) else {
+            panic!("opened a missing file");
+        };
+        assert!(err.starts_with("2025 day 5 (day5): cannot read input /nonexistent/input.txt"));
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_core::Part;
+use std::{
+    env,
+    io::Read,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+mod bench;
+mod cli;
+mod input;
+mod registry;
+mod scaffold;
+mod verify;
+
+use aoc_core::input::Source;
+use bench::{PHASES, Short};
+use cli::Command;
+use input::{day_label, default_path, workspace_root};
+use registry::Day;
+
+fn main() -> ExitCode {
+    let command = match cli::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let solver = registry::find(year, day)
+                .ok_or_else(|| format!("no solver registered for {year} day {day}"))?;
+            let source = Source::from_env(input.as_deref(), default_path(solver));
+            let parts = part.map_or(Part::BOTH.to_vec(), |p| vec![p]);
+            let answers = solver
+                .solve(input::open(&source, solver)?, &parts)
+                .map_err(|e| format!("{}: {e}", solver.name))?;
+            for (part, ans) in parts.iter().zip(answers) {
+                println!("Part {part}: {ans}");
+            }
+            Ok(())
+        }
+        Command::Verify { year, day } => match verify::verify(matching(year, day), |day| {
+            isolated(day, || verify::check(day))
+        }) {
+            0 => Ok(()),
+            failures => Err(format!("{failures} verification check(s) failed")),
+        },
+        Command::Bench {
+            year,
+            day,
+            runs,
+            save,
+            baseline,
+        } => {
+            let path = baseline.map_or_else(bench::default_baseline_path, PathBuf::from);
+            let mut saved = bench::load_baseline(&path)
+                .map_err(|e| format!("cannot read baseline {}: {e}", path.display()))?;
+            let mut fresh = bench::Baseline::new();
+            let mut failures = 0;
+            for solver in matching(year, day) {
+                // one day failing, say with no input or no solution yet, leaves the rest to run
+                if let Err(e) = isolated(solver, || run_bench(solver, runs, &saved, &mut fresh)) {
+                    failures += 1;
+                    println!("ERROR {e}");
+                }
+            }
+            if save {
+                saved.extend(fresh);
+                bench::save_baseline(&path, &saved)
+                    .map_err(|e| format!("cannot write baseline {}: {e}", path.display()))?;
+                println!("saved baseline to {}", path.display());
+            }
+            match failures {
+                0 => Ok(()),
+                failures => Err(format!("{failures} day(s) could not be benchmarked")),
+            }
+        }
+        Command::New { year, day } => {
+            if registry::find(year, day).is_some() {
+                return Err(format!("{year} day {day} is already registered"));
+            }
+            for path in scaffold::scaffold(workspace_root(), year, day)? {
+                println!("wrote {}", path.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The registered days narrowed to `year` and `day`, where given.
+fn matching(year: Option<u32>, day: Option<u32>) -> impl Iterator<Item = &'static Day> {
+    registry::DAYS
+        .iter()
+        .filter(move |d| year.is_none_or(|y| y == d.year) && day.is_none_or(|n| n == d.day))
+}
+
+/// Runs `work` for `day`, turning a panic in its solver, such as the `todo!()` parts of a day
+/// just made by `aoc new`, into an error for that day alone.
+fn isolated<T>(day: &Day, work: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(work)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("{}: panicked: {message}", day_label(day)))
+    })
+}
+
+/// Benchmarks one day on its own input, printing a row per phase, and records the medians.
+fn run_bench(
+    solver: &Day,
+    runs: usize,
+    saved: &bench::Baseline,
+    fresh: &mut bench::Baseline,
+) -> Result<(), String> {
+    let label = day_label(solver);
+    let mut input = Vec::new();
+    input::open(&Source::File(default_path(solver)), solver)?
+        .read_to_end(&mut input)
+        .map_err(|e| format!("{label}: cannot read input: {e}"))?;
+    let summaries = solver
+        .bench(&input, runs)
+        .map_err(|e| format!("{label}: {e}"))?;
+    println!("{label}");
+    println!(
+        "  {:<6} {:>10} {:>10} {:>10} {:>5}  vs baseline",
+        "phase", "min", "median", "p95", "runs"
+    );
+    for (phase, s) in PHASES.iter().zip(summaries) {
+        let key = (solver.year, solver.day, phase.to_string());
+        let versus = saved
+            .get(&key)
+            .map_or("-".to_string(), |&then| bench::change(then, s.median));
+        println!(
+            "  {:<6} {:>10} {:>10} {:>10} {:>5}  {versus}",
+            phase,
+            Short(s.min),
+            Short(s.median),
+            Short(s.p95),
+            s.runs
+        );
+        fresh.insert(key, s.median);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{isolated, registry::find};
+
+    #[test]
+    fn isolates_panicking_days() {
+        let day = find(2025, 1).expect("");
+        let err = isolated::<()>(day, || todo!("part 1")).expect_err("");
+        assert_eq!(
+            err,
+            "2025 day 1 (day1): panicked: not yet implemented: part 1"
+        );
+        assert_eq!(isolated(day, || Ok(7)), Ok(7));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::bench::{self, Summary};
+use aoc_core::{Part, Result, solution};
+use std::io::BufRead;
+
+/// A solver the runner knows how to dispatch to.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    /// The crate implementing the day, which is also its directory in the workspace.
+    pub name: &'static str,
+    solve: fn(&mut dyn BufRead, &[Part]) -> Result<Vec<String>>,
+    bench: fn(&[u8], usize) -> Result<[Summary; 3]>,
+}
+
+impl Day {
+    /// Parses the input once and answers each of `parts` in order.
+    pub fn solve(&self, mut reader: impl BufRead, parts: &[Part]) -> Result<Vec<String>> {
+        (self.solve)(&mut reader, parts)
+    }
+
+    /// Times parsing and each part over `input`, in the order of [`bench::PHASES`].
+    pub fn bench(&self, input: &[u8], runs: usize) -> Result<[Summary; 3]> {
+        (self.bench)(input, runs)
+    }
+}
+
+/// Registers the `Solution` implemented by a day crate.
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident :: $solution:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            name: stringify!($krate),
+            solve: solution::solve::<$krate::$solution>,
+            bench: bench::phases::<$krate::$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2025, 1, day1::Day1),
+    day!(2025, 2, day2::Day2),
+    day!(2025, 3, day3_v2::Day3),
+    day!(2025, 4, day4::Day4),
+    day!(2025, 5, day5::Day5),
+    day!(2025, 6, day6::Day6),
+    day!(2025, 7, day7::Day7),
+    // aoc new registers days above this line.
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{DAYS, find};
+
+    #[test]
+    fn days_are_unique() {
+        for (i, a) in DAYS.iter().enumerate() {
+            for b in &DAYS[i + 1..] {
+                assert!(
+                    (a.year, a.day) != (b.year, b.day),
+                    "{} and {}",
+                    a.name,
+                    b.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_registered_days() {
+        assert_eq!(find(2025, 4).map(|d| d.name), Some("day4"));
+        assert!(find(2025, 26).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The line in `registry.rs` that new days are inserted above.
+pub const REGISTRY_MARKER: &str = "    // aoc new registers days above this line.";
+
+/// Creates the `day<N>` crate for a new puzzle under the workspace at `root` and wires it into
+/// the workspace members, the runner's dependencies and its registry. Returns every path written.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let krate = format!("day{day}");
+    let dir = root.join(&krate);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let registry = root.join("aoc/src/registry.rs");
+    let workspace = edit(&root.join("Cargo.toml"), |text| insert_member(text, &krate))?;
+    let runner = edit(&root.join("aoc/Cargo.toml"), |text| {
+        insert_dependency(text, &krate)
+    })?;
+    let days = edit(&registry, |text| insert_day(text, year, day, &krate))?;
+
+    let solution = format!("Day{day}");
+    let files = [
+        ("Cargo.toml", manifest(&krate)),
+        ("build.rs", BUILD.to_string()),
+        ("src/lib.rs", skeleton(&solution)),
+        ("data/examples/example.txt", String::new()),
+        ("data/examples/answers.toml", EXAMPLE_ANSWERS.to_string()),
+    ];
+    let mut written = Vec::new();
+    for (file, contents) in files {
+        let path = dir.join(file);
+        let parent = path.parent().expect("scaffolded files live in the crate");
+        fs::create_dir_all(parent)
+            .and_then(|()| fs::write(&path, contents))
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        written.push(path);
+    }
+    // only touch existing files once the new crate is in place
+    for (path, text) in [
+        (root.join("Cargo.toml"), workspace),
+        (root.join("aoc/Cargo.toml"), runner),
+        (registry, days),
+    ] {
+        fs::write(&path, text).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Reads `path` and returns the text `change` makes of it, without writing it back.
+fn edit<F>(path: &Path, change: F) -> Result<String, String>
+where
+    F: FnOnce(&str) -> Option<String>,
+{
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    change(&text).ok_or_else(|| format!("cannot find where to add the day in {}", path.display()))
+}
+
+/// Appends `krate` to the end of the workspace `members` list.
+fn insert_member(text: &str, krate: &str) -> Option<String> {
+    let list = text.find("members = [")?;
+    let end = list + text[list..].find("\n]")?;
+    Some(format!(
+        "{}\n    \"{krate}\",{}",
+        &text[..end],
+        &text[end..]
+    ))
+}
+
+/// Appends a path dependency on `krate` to the end of the `[dependencies]` table.
+fn insert_dependency(text: &str, krate: &str) -> Option<String> {
+    let table = text.find("[dependencies]")?;
+    let end = text[table..]
+        .find("\n[")
+        .map_or(text.trim_end().len(), |i| table + i);
+    Some(format!(
+        "{}\n{krate} = {{ path = \"../{krate}\" }}{}",
+        text[..end].trim_end(),
+        &text[end..]
+    ))
+}
+
+/// Adds a `day!` entry for the new crate just above [`REGISTRY_MARKER`].
+fn insert_day(text: &str, year: u32, day: u32, krate: &str) -> Option<String> {
+    let at = text.find(REGISTRY_MARKER)?;
+    Some(format!(
+        "{}    day!({year}, {day}, {krate}::Day{day}),\n{}",
+        &text[..at],
+        &text[at..]
+    ))
+}
+
+fn manifest(krate: &str) -> String {
+    format!(
+        "\
+[package]
+name = \"{krate}\"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-core.workspace = true
+
+[build-dependencies]
+aoc-core.workspace = true
+"
+    )
+}
+
+const BUILD: &str = "\
+fn main() {
+    aoc_core::fixtures::generate_tests();
+}
+";
+
+fn skeleton(solution: &str) -> String {
+    format!(
+        "\
+use aoc_core::{{Result, Solution, input::numbered_lines}};
+use std::io::BufRead;
+
+pub struct {solution};
+
+impl Solution for {solution} {{
+    type Parsed = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<String>> {{
+        numbered_lines(reader)
+            .map(|numbered| Ok(numbered?.1))
+            .collect()
+    }}
+
+    fn part1(_lines: &Vec<String>) -> u64 {{
+        todo!(\"part 1\")
+    }}
+
+    fn part2(_lines: &Vec<String>) -> u64 {{
+        todo!(\"part 2\")
+    }}
+}}
+
+aoc_core::example_tests!({solution});
+"
+    )
+}
+
+/// Placeholder answers, so the example test fails until the real ones are filled in.
+const EXAMPLE_ANSWERS: &str = "\
+# Answers to each example in this directory, in a table named after its file.
+# Paste the example into example.txt and replace these with the answers from the puzzle.
+[example]
+part1 = 0
+part2 = 0
+";
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{REGISTRY_MARKER, scaffold};
+    use std::{env, fs};
+
+    #[test]
+    fn scaffolds_a_registered_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).expect("");
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day7\",\n]\n\n[workspace.package]\n",
+        )
+        .expect("");
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nday7 = { path = \"../day7\" }\n",
+        )
+        .expect("");
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            format!("pub const DAYS: &[Day] = &[\n    day!(2025, 7, day7::Day7),\n{REGISTRY_MARKER}\n];\n"),
+        )
+        .expect("");
+
+        let written = scaffold(&root, 2025, 8).expect("");
+        assert_eq!(written.len(), 8);
+        let read = |path: &str| fs::read_to_string(root.join(path)).expect("");
+        assert!(
+            read("Cargo.toml").contains("    \"day7\",\n    \"day8\",\n]\n\n[workspace.package]")
+        );
+        assert!(read("aoc/Cargo.toml").ends_with("day8 = { path = \"../day8\" }\n"));
+        assert!(read("aoc/src/registry.rs").contains(&format!(
+            "day7::Day7),\n    day!(2025, 8, day8::Day8),\n{REGISTRY_MARKER}"
+        )));
+        assert!(read("day8/Cargo.toml").contains("name = \"day8\""));
+        assert!(read("day8/src/lib.rs").contains("impl Solution for Day8 {"));
+        assert!(read("day8/data/examples/answers.toml").contains("[example]"));
+
+        let err = scaffold(&root, 2025, 8).expect_err("");
+        assert!(err.ends_with("day8 already exists"));
+        fs::remove_dir_all(&root).expect("");
+    }
+
+    #[test]
+    fn leaves_the_workspace_alone_without_a_marker() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-bare-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).expect("");
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n]\n").expect("");
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").expect("");
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "pub const DAYS: &[Day] = &[];\n",
+        )
+        .expect("");
+
+        assert!(scaffold(&root, 2025, 9).is_err());
+        assert!(!root.join("day9").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).expect(""),
+            "[workspace]\nmembers = [\n]\n"
+        );
+        fs::remove_dir_all(&root).expect("");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    input::{day_label, default_path, open},
+    registry::Day,
+};
+use aoc_core::{Part, answers, input::Source};
+use std::{fs, io, path::PathBuf};
+
+/// The file holding a day's confirmed answers, next to its `data/input.txt`.
+pub fn answers_path(day: &Day) -> PathBuf {
+    default_path(day).with_file_name("answers.toml")
+}
+
+/// One part's rerun answer alongside what was recorded for it.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Check {
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.expected.as_ref().is_none_or(|e| *e == self.actual)
+    }
+}
+
+/// Reruns `day` on its own input and pairs each part with its recorded answer. Returns `None`
+/// when the day has no answers file yet.
+pub fn check(day: &Day) -> Result<Option<Vec<Check>>, String> {
+    let path = answers_path(day);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+    };
+    let recorded = answers::parse(&text)
+        .map_err(|e| format!("{}: {e}", path.display()))?
+        .remove("")
+        .unwrap_or_default();
+    let source = Source::File(default_path(day));
+    let actual = day
+        .solve(open(&source, day)?, &Part::BOTH)
+        .map_err(|e| format!("{}: {e}", day_label(day)))?;
+    Ok(Some(
+        Part::BOTH
+            .into_iter()
+            .zip(actual)
+            .map(|(part, actual)| Check {
+                part,
+                expected: recorded.get(part).map(String::from),
+                actual,
+            })
+            .collect(),
+    ))
+}
+
+/// Checks every day in `days` with `check`, printing a line per part. Returns how many checks
+/// failed.
+pub fn verify<'a, F>(days: impl Iterator<Item = &'a Day>, check: F) -> usize
+where
+    F: Fn(&Day) -> Result<Option<Vec<Check>>, String>,
+{
+    let mut failures = 0;
+    for day in days {
+        let label = day_label(day);
+        match check(day) {
+            Ok(None) => println!("{label}: no answers recorded"),
+            Ok(Some(checks)) => {
+                for c in checks {
+                    match &c.expected {
+                        None => println!("{label} part {}: unrecorded {}", c.part, c.actual),
+                        Some(_) if c.passed() => {
+                            println!("{label} part {}: ok {}", c.part, c.actual)
+                        }
+                        Some(expected) => {
+                            failures += 1;
+                            println!(
+                                "{label} part {}: MISMATCH expected {expected}, got {}",
+                                c.part, c.actual
+                            );
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                failures += 1;
+                println!("{label}: ERROR {e}");
+            }
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify::Check;
+    use aoc_core::Part;
+
+    #[test]
+    fn unrecorded_parts_pass() {
+        let check = |expected: Option<&str>| Check {
+            part: Part::One,
+            expected: expected.map(String::from),
+            actual: "13".to_string(),
+        };
+        assert!(check(None).passed());
+        assert!(check(Some("13")).passed());
+        assert!(!check(Some("14")).passed());
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_core::{AocError, Result, Solution, input::numbered_lines};
+use std::io::BufRead;
+
+const DIAL: i64 = 100;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Signed rotations, negative to the left.
+    type Parsed = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<i64>> {
+        let mut rotations = Vec::new();
+        for numbered in numbered_lines(reader) {
+            let (line_no, line) = numbered?;
+            let mut chars = line.chars();
+            let dir = chars.next().ok_or_else(|| {
+                AocError::parse(line_no, 1, &line, "expected a rotation like L68")
+            })?;
+            let amount = chars.as_str();
+            let mvmt: i64 = amount
+                .parse()
+                .map_err(|e| AocError::parse(line_no, 2, amount, e))?;
+            rotations.push(match dir {
+                'L' => -mvmt,
+                'R' => mvmt,
+                _ => {
+                    return Err(AocError::parse(
+                        line_no,
+                        1,
+                        &dir.to_string(),
+                        "direction must be L or R",
+                    ));
+                }
+            });
+        }
+        Ok(rotations)
+    }
+
+    fn part1(rotations: &Vec<i64>) -> i64 {
+        count_zeros(rotations, part_1)
+    }
+
+    fn part2(rotations: &Vec<i64>) -> i64 {
+        count_zeros(rotations, part_2)
+    }
+}
+
+fn count_zeros<F>(rotations: &[i64], zeros: F) -> i64
+where
+    F: Fn(i64, i64) -> i64,
+{
+    let mut cur = 50;
+    let mut ans = 0;
+    for mv in rotations {
+        let raw = cur + mv;
+        ans += zeros(cur, raw);
+        cur = raw.rem_euclid(DIAL);
+    }
+    ans
+}
+
+/// Counts the rotations that leave the dial pointing at zero.
+pub(crate) fn part_1(_: i64, raw: i64) -> i64 {
+    if raw.rem_euclid(DIAL) == 0 { 1 } else { 0 }
+}
+
+/// Counts every click that passes over zero, including where a rotation stops.
+pub(crate) fn part_2(cur: i64, raw: i64) -> i64 {
+    if raw <= 0 {
+        (if cur == 0 { 0 } else { 1 }) + (raw.abs() / DIAL)
+    } else if raw >= DIAL {
+        raw / DIAL
+    } else {
+        0
+    }
+}
+
+aoc_core::example_tests!(Day1);
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+    use aoc_core::{Solution, input::reader};
+
+    #[test]
+    fn bad_rotations() {
+        let err = |input| Day1::parse(reader(input)).expect_err("").position();
+        assert_eq!(err("L68\nX30"), Some((2, 1)));
+        assert_eq!(err("L68\nR3o"), Some((2, 2)));
+        assert_eq!(err("L68\n\nR3"), Some((2, 1)));
+    }
+}
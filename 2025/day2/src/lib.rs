@@ -0,0 +1,140 @@
+use aoc_core::{AocError, Result, Solution, range::parse_range};
+use std::{collections::HashSet, io::BufRead, ops::RangeInclusive};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<RangeInclusive<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<RangeInclusive<i64>>> {
+        let mut ranges = Vec::new();
+        // (line, column) of the current chunk; the list may be one very long line.
+        let mut pos = (1, 1);
+        for chunk_result in reader.split(b',') {
+            let chunk = chunk_result?;
+            let content = String::from_utf8(chunk).map_err(|e| {
+                let text = String::from_utf8_lossy(e.as_bytes()).into_owned();
+                AocError::parse(pos.0, pos.1, &text, "not valid UTF-8")
+            })?;
+            let trimmed = content.trim();
+            if !trimmed.is_empty() {
+                let (line, column) =
+                    advance(pos, &content[..content.len() - content.trim_start().len()]);
+                ranges.push(parse_range(trimmed).map_err(|e| e.shifted(line, column))?);
+            }
+            pos = advance(pos, &content);
+            // step over the comma
+            pos.1 += 1;
+        }
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Vec<RangeInclusive<i64>>) -> i64 {
+        find_invalid(ranges, is_double)
+    }
+
+    fn part2(ranges: &Vec<RangeInclusive<i64>>) -> i64 {
+        find_invalid(ranges, is_duplicate)
+    }
+}
+
+/// Moves a (line, column) position past `text`.
+fn advance((mut line, mut column): (usize, usize), text: &str) -> (usize, usize) {
+    for c in text.chars() {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+pub fn find_invalid<F>(ranges: &[RangeInclusive<i64>], is_invalid: F) -> i64
+where
+    F: Fn(&str) -> bool,
+{
+    let mut ans = 0;
+    for x in ranges.iter().flat_map(|r| r.clone()) {
+        if is_invalid(&x.to_string()) {
+            ans += x;
+        }
+    }
+    ans
+}
+
+/**
+ * Part 2
+ *
+ * After the first, for each char in the string that is the same as the first, consider everything
+ * we've seen before that a possible repeater. Stack up these repeaters until we can invalidate
+ * them via: at the end their length they don't divide into the string length or at any point they
+ * don't continue to repeat. True (has duplicating pattern) means that there were repeaters left in
+ * the set at the very end.
+ */
+pub fn is_duplicate(x: &str) -> bool {
+    if x.len() < 2 {
+        return false;
+    }
+    let first = &x[0..1];
+    let mut possibles: HashSet<&str> = HashSet::new();
+    possibles.insert(first);
+    for i in 1..x.len() {
+        let cur = &x[i..i + 1];
+        if cur == first {
+            let repeater = &x[..i];
+            if x.len().is_multiple_of(repeater.len()) {
+                possibles.insert(repeater);
+            }
+        }
+        possibles.retain(|p| {
+            // the index into p as if we kept repeating it
+            let j = i % p.len();
+            // if p[j] != current char, it doesn't continue to repeat, trash it
+            *cur == p[j..j + 1]
+        });
+    }
+    !possibles.is_empty()
+}
+
+/** Part 1 */
+pub fn is_double(x: &str) -> bool {
+    if x.len() % 2 == 1 {
+        return false;
+    };
+    let (start, end) = x.split_at(x.len() / 2);
+    for (x, y) in start.chars().zip(end.chars()) {
+        if x != y {
+            return false;
+        };
+    }
+    true
+}
+
+aoc_core::example_tests!(Day2);
+
+#[cfg(test)]
+mod tests {
+    use crate::Day2;
+    use aoc_core::{Solution, input::reader};
+    use std::io::BufReader;
+
+    #[test]
+    fn bad_range() {
+        let err = Day2::parse(reader("11-22,95-1x5,\n3-4")).expect_err("");
+        assert_eq!(err.position(), Some((1, 10)));
+        let err = Day2::parse(reader("11-22,\n 95")).expect_err("");
+        assert_eq!(err.position(), Some((2, 2)));
+    }
+
+    #[test]
+    fn part2_streamed() {
+        let example = include_str!("../data/examples/example.txt");
+        let streamed = BufReader::with_capacity(3, example.as_bytes());
+        let ranges = Day2::parse(streamed).expect("");
+        assert_eq!(Day2::part2(&ranges), 4174379265);
+    }
+}
@@ -1,37 +1,51 @@
+use aoc_core::{
+    Result, Solution,
+    digits::{digit_lines, from_digits},
+};
 use core::fmt;
 use std::{
     cmp::Ordering::{self, Less},
     collections::VecDeque,
     fmt::Debug,
-    io::{self, BufRead, Cursor},
+    io::BufRead,
 };
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The digits of each battery bank.
+    type Parsed = Vec<Vec<u32>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<Vec<u32>>> {
+        digit_lines(reader).collect()
+    }
+
+    fn part1(banks: &Vec<Vec<u32>>) -> u64 {
+        joltage(banks, part_1::<2>)
+    }
+
+    fn part2(banks: &Vec<Vec<u32>>) -> u64 {
+        joltage(banks, part_1::<12>)
+    }
+}
+
 // This was way too slow for 12, when you look at the big, duh.
-pub(crate) fn joltage<F>(reader: io::BufReader<Cursor<&str>>, get_joltage: F) -> io::Result<u64>
+pub(crate) fn joltage<F>(banks: &[Vec<u32>], get_joltage: F) -> u64
 where
-    F: Fn(Vec<u32>) -> u64,
+    F: Fn(&[u32]) -> u64,
 {
-    let mut ans = 0;
-    for line_result in reader.lines() {
-        let line = line_result?;
-        let trimmed = line.trim();
-        ans += get_joltage(trimmed.chars().map(|n| n.to_digit(10).unwrap()).collect());
-    }
-    Ok(ans)
+    banks.iter().map(|bank| get_joltage(bank)).sum()
 }
 
-pub(crate) fn part_1<const N: usize>(nums: Vec<u32>) -> u64 {
+pub(crate) fn part_1<const N: usize>(nums: &[u32]) -> u64 {
     let mut tree = Tree::<_, N>::new(u32::DEFAULT);
-    for num in nums {
+    for &num in nums {
         tree.insert(num);
     }
     let ans = tree.max();
-    let base: u64 = 10;
-    let mut res: u64 = 0;
-    for (i, n) in ans.iter().rev().enumerate() {
-        res += base.pow(i as u32) * (*n as u64);
-    }
-    res
+    from_digits(&ans)
 }
 
 trait Default {
@@ -73,7 +87,7 @@ impl<T: Ord + Copy + Default + Debug> Node<T> {
     }
 
     pub(crate) fn cmp_max_child_to(&self, other: T) -> Option<Ordering> {
-        self.children.get(0).map(|child| child.value.cmp(&other))
+        self.children.front().map(|child| child.value.cmp(&other))
     }
 
     fn fmt_with_depth(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
@@ -123,7 +137,7 @@ impl<T: Ord + Copy + Default + fmt::Debug, const MAX_DEPTH: usize> Tree<T, MAX_D
             return depth;
         }
         let mut deepest_child = depth;
-        if node.cmp_max_child_to(val).map_or(true, |res| res == Less) {
+        if node.cmp_max_child_to(val).is_none_or(|res| res == Less) {
             node.children.push_front(Node::new(val, depth + 1));
             deepest_child = depth + 1;
         }
@@ -139,21 +153,21 @@ impl<T: Ord + Copy + Default + fmt::Debug, const MAX_DEPTH: usize> Tree<T, MAX_D
         }
         node.deepest_leaf = deepest_child;
         // we can prune a bit
-        let max_child_node = node.children.get(0).cloned();
+        let max_child_node = node.children.front().cloned();
         let mut index = 0;
         node.children.retain(|child| {
             let cur_index = index;
             index += 1;
             if max_child_node
                 .clone()
-                .map_or(false, |n| child.deepest_leaf < n.deepest_leaf)
+                .is_some_and(|n| child.deepest_leaf < n.deepest_leaf)
                 && cur_index > 0
             {
                 return false;
             }
             true
         });
-        return deepest_child;
+        deepest_child
     }
 
     pub(crate) fn max(&self) -> [T; MAX_DEPTH] {
@@ -180,68 +194,16 @@ impl<T: Ord + Copy + Default + fmt::Debug, const MAX_DEPTH: usize> Tree<T, MAX_D
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = include_str!("../data/input.txt");
-    let reader = io::BufReader::new(Cursor::new(input));
-    let ans = joltage(reader, part_1::<12>)?;
-    println!("{ans}");
-    Ok(())
-}
+aoc_core::example_tests!(Day3);
 
 #[cfg(test)]
 mod tests {
-    use crate::{joltage, part_1};
-    use std::io::{self, Cursor};
-
-    const TEST_INPUT: &str = "\
-        987654321111111
-        811111111111119
-        234234234234278
-        818181911112111";
-    const TEST_INPUT_1: &str = "\
-        987654321111111";
-    const TEST_INPUT_2: &str = "\
-        811111111111119";
-    const TEST_INPUT_3: &str = "\
-        234234234234278";
-    // const TEST_INPUT_4: &str = "\
-    //     818181911112111";
-
-    #[test]
-    fn part1() {
-        let reader = io::BufReader::new(Cursor::new(TEST_INPUT));
-        let ans = joltage(reader, part_1::<2>).expect("");
-        assert_eq!(ans, 357);
-    }
+    use crate::{Day3, joltage, part_1};
+    use aoc_core::{Solution, input::reader};
 
-    #[test]
-    fn part2() {
-        let reader = io::BufReader::new(Cursor::new(TEST_INPUT));
-        let ans = joltage(reader, part_1::<12>).expect("");
-        assert_eq!(ans, 3121910778619);
-    }
-    #[test]
-    fn part2_1() {
-        let reader = io::BufReader::new(Cursor::new(TEST_INPUT_1));
-        let ans = joltage(reader, part_1::<12>).expect("");
-        assert_eq!(ans, 987654321111);
-    }
-    #[test]
-    fn part2_2() {
-        let reader = io::BufReader::new(Cursor::new(TEST_INPUT_2));
-        let ans = joltage(reader, part_1::<12>).expect("");
-        assert_eq!(ans, 811111111119);
-    }
-    #[test]
-    fn part2_3() {
-        let reader = io::BufReader::new(Cursor::new(TEST_INPUT_3));
-        let ans = joltage(reader, part_1::<12>).expect("");
-        assert_eq!(ans, 434234234278);
-    }
     #[test]
     fn part2_4() {
-        let reader = io::BufReader::new(Cursor::new("818911"));
-        let ans = joltage(reader, part_1::<5>).expect("");
-        assert_eq!(ans, 88911);
+        let banks = Day3::parse(reader("818911")).expect("");
+        assert_eq!(joltage(&banks, part_1::<5>), 88911);
     }
 }
@@ -0,0 +1,85 @@
+use aoc_core::{
+    AocError, Result, Solution,
+    digits::{digit_lines, from_digits},
+};
+use std::{io::BufRead, mem::swap};
+
+const BATTERIES: usize = 12;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The digits of each battery bank.
+    type Parsed = Vec<Vec<u32>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<Vec<u32>>> {
+        let banks: Vec<Vec<u32>> = digit_lines(reader).collect::<Result<_>>()?;
+        if let Some(i) = banks.iter().position(Vec::is_empty) {
+            return Err(AocError::shape(
+                i + 1,
+                1,
+                "",
+                "a bank needs at least one battery",
+            ));
+        }
+        Ok(banks)
+    }
+
+    fn part1(banks: &Vec<Vec<u32>>) -> u64 {
+        joltage(banks, sift::<2>)
+    }
+
+    fn part2(banks: &Vec<Vec<u32>>) -> u64 {
+        joltage(banks, sift::<BATTERIES>)
+    }
+}
+
+// The insight is to go backwards.
+pub(crate) fn joltage<F>(banks: &[Vec<u32>], get_joltage: F) -> u64
+where
+    F: Fn(&[u32]) -> u64,
+{
+    banks.iter().map(|bank| get_joltage(bank)).sum()
+}
+
+/// The largest joltage from turning on `N` batteries, or every battery of a bank with fewer.
+pub(crate) fn sift<const N: usize>(nums: &[u32]) -> u64 {
+    if nums.len() <= N {
+        return from_digits(nums);
+    }
+    let back = &nums[nums.len() - N..];
+    let front = &nums[..nums.len() - N];
+    let mut ans = [0; N];
+    ans.copy_from_slice(back);
+    for n in front.iter().rev() {
+        let mut cur = *n;
+        for slot in ans.iter_mut() {
+            if cur >= *slot {
+                swap(&mut cur, slot);
+            } else {
+                break;
+            };
+        }
+    }
+    from_digits(&ans)
+}
+
+aoc_core::example_tests!(Day3);
+
+#[cfg(test)]
+mod tests {
+    use crate::Day3;
+    use aoc_core::{Solution, input::reader};
+
+    #[test]
+    fn short_banks() {
+        // part 1 only needs two batteries a bank; part 2 turns on all of a shorter bank
+        let banks = Day3::parse(reader("987654321111111\n8111")).expect("");
+        assert_eq!(Day3::part1(&banks), 98 + 81);
+        assert_eq!(Day3::part2(&banks), 987654321111 + 8111);
+        let err = Day3::parse(reader("81\n\n91")).expect_err("");
+        assert_eq!(err.position(), Some((2, 1)));
+    }
+}
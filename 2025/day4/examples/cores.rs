@@ -0,0 +1,36 @@
+//! Draws each roll's core number as a heatmap, then how many rolls a cascade removes for every
+//! threshold:
+//!
+//!     cargo run -p day4 --example cores [input | -]
+
+use aoc_core::{
+    Solution, cli,
+    render::{self, Rgb, Style, stdout_has_color},
+};
+use day4::{Day4, cores, removed_below};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cli::run(env!("CARGO_MANIFEST_DIR"), &[], Day4::parse, |_, floor| {
+        let cores = cores(&floor);
+        let deepest = cores.iter().filter_map(|(_, c)| *c).max().unwrap_or(0);
+        let heat = |p| {
+            cores[p].map(|c| Style::fg(Rgb::BLUE.lerp(Rgb::RED, c as f64 / deepest.max(1) as f64)))
+        };
+        let glyph = |core: &Option<usize>| match core {
+            Some(c) => char::from_digit(*c as u32, 36).unwrap_or('+'),
+            None => '.',
+        };
+        print!(
+            "{}",
+            render::render(&cores, glyph, &[&heat], stdout_has_color())
+        );
+        for k in 1..=deepest + 1 {
+            println!(
+                "fewer than {k} neighbors: {} rolls removed",
+                removed_below(&cores, k)
+            );
+        }
+        Ok(())
+    })
+}
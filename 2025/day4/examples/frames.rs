@@ -0,0 +1,34 @@
+//! Writes one image per removal wave of the cascade, for stitching into an animation:
+//!
+//!     cargo run -p day4 --example frames [--ppm] [--out=frames] [input | -]
+//!     ffmpeg -framerate 4 -i frames/frame-%04d.png cascade.gif
+
+use aoc_core::{Solution, cli, image::Format};
+use day4::{Day4, frames};
+use std::{path::Path, process::ExitCode};
+
+/// Pixels along each side of a cell.
+const SCALE: usize = 4;
+
+fn main() -> ExitCode {
+    cli::run(
+        env!("CARGO_MANIFEST_DIR"),
+        &["--ppm", "--out"],
+        Day4::parse,
+        |args, floor| {
+            let format = if args.switch("--ppm") {
+                Format::Ppm
+            } else {
+                Format::Png
+            };
+            let dir = Path::new(args.value("--out").unwrap_or("frames"));
+            let written =
+                aoc_core::image::write_frames(dir, format, &frames(&floor), SCALE, |_, spot| {
+                    spot.color()
+                })
+                .map_err(|e| format!("cannot write frames to {}: {e}", dir.display()))?;
+            println!("wrote {} frames to {}", written.len(), dir.display());
+            Ok(())
+        },
+    )
+}
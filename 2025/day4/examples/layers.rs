@@ -0,0 +1,23 @@
+//! Reports how the cascade unfolds round by round, optionally listing the rolls in each:
+//!
+//!     cargo run -p day4 --example layers [--rolls] [input | -]
+
+use aoc_core::{Solution, cli};
+use day4::{Day4, layers};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cli::run(
+        env!("CARGO_MANIFEST_DIR"),
+        &["--rolls"],
+        Day4::parse,
+        |args, floor| {
+            if args.switch("--rolls") {
+                println!("{:#}", layers(&floor));
+            } else {
+                println!("{}", layers(&floor));
+            }
+            Ok(())
+        },
+    )
+}
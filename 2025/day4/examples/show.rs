@@ -0,0 +1,14 @@
+//! Draws a floor, by default the day's own input, marking the rolls that can go first:
+//!
+//!     cargo run -p day4 --example show [input | -]
+
+use aoc_core::{Solution, cli, render::stdout_has_color};
+use day4::{Day4, render};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cli::run(env!("CARGO_MANIFEST_DIR"), &[], Day4::parse, |_, floor| {
+        print!("{}", render(&floor, stdout_has_color()));
+        Ok(())
+    })
+}
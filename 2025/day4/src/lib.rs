@@ -0,0 +1,439 @@
+use aoc_core::{
+    Result, Solution,
+    grid::{Edge, Grid},
+    neighborhood::Neighborhood,
+    peel::core_numbers,
+    point::Point,
+    render::{self, Rgb, Style},
+};
+use std::{collections::HashSet, fmt, io::BufRead, mem};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Floor;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(reader: impl BufRead) -> Result<Floor> {
+        forklift(reader, Neighborhood::moore(), Edge::Bounded)
+    }
+
+    fn part1(floor: &Floor) -> i32 {
+        part_1(floor)
+    }
+
+    fn part2(floor: &Floor) -> i32 {
+        part_2(floor)
+    }
+}
+
+/// A roll with this many neighbors or more is too crowded for a forklift to reach.
+const CROWDED: i32 = 4;
+
+/// The warehouse floor as the forklifts first find it.
+#[derive(Debug)]
+pub struct Floor {
+    /// Which cells count as a roll's neighbors.
+    neighborhood: Neighborhood,
+    /// Whether each cell holds a roll, with what lies past the edge.
+    cells: Grid<bool>,
+    /// Rolls with fewer than four neighbors, which can be removed straight away.
+    removes: Vec<Point<i32>>,
+    /// Neighbor count of every roll still on the floor, `None` for empty cells. Lookups past
+    /// the edge wrap or clamp the same way the floor was read.
+    rolls: Grid<Option<i32>>,
+}
+
+/// Reads the floor, counting as neighbors the cells `neighborhood` reaches and, past the edge,
+/// whatever `edge` finds there.
+pub fn forklift(
+    reader: impl BufRead,
+    neighborhood: Neighborhood,
+    edge: Edge<bool>,
+) -> Result<Floor> {
+    let floor = Grid::parse(reader, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?
+    .with_edge(edge);
+    let rolls = floor.map(|roll, &is_roll| {
+        is_roll.then(|| {
+            neighborhood
+                .around(roll)
+                .filter(|&n| floor.get(n) == Some(&true))
+                .count() as i32
+        })
+    });
+    let removes = rolls
+        .iter()
+        .filter(|(_, count)| count.is_some_and(|c| c < CROWDED))
+        .map(|(roll, _)| roll)
+        .collect();
+    Ok(Floor {
+        neighborhood,
+        cells: floor,
+        removes,
+        rolls,
+    })
+}
+
+pub(crate) fn part_1(floor: &Floor) -> i32 {
+    floor.removes.len() as i32
+}
+
+/// Every roll the cascade removes, however many rounds it takes.
+pub(crate) fn part_2(floor: &Floor) -> i32 {
+    layers(floor).total() as i32
+}
+
+/// The cascade taken breadth first: each round removes every roll that can go at that point,
+/// so the first round matches part 1 and the rounds together match part 2.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Layers {
+    /// The rolls removed in each round, row by row.
+    pub rounds: Vec<Vec<Point<i32>>>,
+}
+
+impl Layers {
+    /// How many rolls each round removes.
+    pub fn counts(&self) -> Vec<usize> {
+        self.rounds.iter().map(Vec::len).collect()
+    }
+
+    pub fn total(&self) -> usize {
+        self.rounds.iter().map(Vec::len).sum()
+    }
+}
+
+/// One line per round with its count, then the totals. The alternate form, `{:#}`, also lists
+/// each round's rolls as `row,column`, counting from 0.
+impl fmt::Display for Layers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, round) in self.rounds.iter().enumerate() {
+            write!(f, "round {}: {} rolls", i + 1, round.len())?;
+            if f.alternate() {
+                write!(f, " ->")?;
+                for roll in round {
+                    write!(f, " {},{}", roll.y, roll.x)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "stable after {} rounds, {} rolls removed",
+            self.rounds.len(),
+            self.total()
+        )
+    }
+}
+
+/// Runs the cascade round by round: first every roll that can go straight away, then every roll
+/// the round before freed up, until the floor is stable.
+pub fn layers(floor: &Floor) -> Layers {
+    // a roll going lowers the count of every roll that counted it, which need not be its own
+    // neighbors when the neighborhood is lopsided or the floor wraps or clamps
+    let counted_by = floor.neighborhood.counted_by(&floor.cells);
+    let mut rolls = floor.rolls.clone();
+    let mut round = floor.removes.clone();
+    let mut rounds = Vec::new();
+    while !round.is_empty() {
+        // a wrapped or clamped neighborhood can reach back to the roll itself
+        for &roll in &round {
+            rolls[roll] = None;
+        }
+        let mut next = Vec::new();
+        for &roll in &round {
+            for &counter in &counted_by[roll] {
+                if let Some(x) = rolls[counter].as_mut() {
+                    if *x == CROWDED {
+                        next.push(counter);
+                    }
+                    *x -= 1;
+                }
+            }
+        }
+        round.sort();
+        rounds.push(mem::replace(&mut round, next));
+    }
+    Layers { rounds }
+}
+
+/// Each roll's core number: the most neighbors it can demand and still never be removed. A
+/// cascade that removes rolls with fewer than `k` neighbors takes exactly the rolls whose core
+/// number is below `k`, so one pass answers part 2 for every threshold.
+pub fn cores(floor: &Floor) -> Grid<Option<usize>> {
+    core_numbers(&floor.cells, &floor.neighborhood, |&roll| roll)
+}
+
+/// How many rolls a cascade removing rolls with fewer than `k` neighbors takes away.
+pub fn removed_below(cores: &Grid<Option<usize>>, k: usize) -> usize {
+    cores
+        .iter()
+        .filter(|(_, core)| core.is_some_and(|c| c < k))
+        .count()
+}
+
+/// What a cell of the floor holds partway through the cascade.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Spot {
+    Empty,
+    Roll,
+    /// A roll taken away in the round being shown.
+    Removing,
+    /// A roll taken away in an earlier round.
+    Removed,
+}
+
+impl Spot {
+    pub fn color(self) -> Rgb {
+        match self {
+            Spot::Empty => Rgb::BLACK,
+            Spot::Roll => Rgb::WHITE,
+            Spot::Removing => Rgb::RED,
+            Spot::Removed => Rgb::GREY,
+        }
+    }
+}
+
+/// One picture of the floor per round of [`layers`], showing that round's rolls being removed,
+/// and a last one of the floor once it is stable.
+pub fn frames(floor: &Floor) -> Vec<Grid<Spot>> {
+    let mut spots = floor.rolls.map(|_, roll| {
+        if roll.is_some() {
+            Spot::Roll
+        } else {
+            Spot::Empty
+        }
+    });
+    let mut frames = Vec::new();
+    for round in layers(floor).rounds {
+        for &roll in &round {
+            spots[roll] = Spot::Removing;
+        }
+        frames.push(spots.clone());
+        for &roll in &round {
+            spots[roll] = Spot::Removed;
+        }
+    }
+    frames.push(spots);
+    frames
+}
+
+/// Draws the floor with every roll shaded from green to red by how crowded it is, and the rolls
+/// that can be removed straight away marked `x` on blue.
+pub fn render(floor: &Floor, color: bool) -> String {
+    let most = floor.neighborhood.offsets().len().max(1) as f64;
+    let crowding =
+        |p| floor.rolls[p].map(|count| Style::fg(Rgb::GREEN.lerp(Rgb::RED, count as f64 / most)));
+    let removable: HashSet<Point<i32>> = floor.removes.iter().copied().collect();
+    let removes = |p| {
+        removable.contains(&p).then_some(Style {
+            glyph: Some('x'),
+            bg: Some(Rgb::BLUE),
+            ..Style::default()
+        })
+    };
+    let glyph = |roll: &Option<i32>| if roll.is_some() { '@' } else { '.' };
+    render::render(&floor.rolls, glyph, &[&crowding, &removes], color)
+}
+
+aoc_core::example_tests!(Day4);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Day4, Floor, Spot, cores, forklift, frames, layers, part_1, part_2, removed_below, render,
+    };
+    use aoc_core::point::Point;
+    use aoc_core::{Solution, grid::Edge, input::reader, neighborhood::Neighborhood};
+
+    #[test]
+    fn malformed_floor() {
+        let err = |input| Day4::parse(reader(input)).expect_err("").position();
+        assert_eq!(err("..@\n.@\n@.."), Some((2, 1)));
+        assert_eq!(err("..@\n.x@"), Some((2, 2)));
+    }
+
+    #[test]
+    fn other_neighborhoods() {
+        let block = "@@@\n@@@\n@@@";
+        let answers = |neighborhood| {
+            let floor = forklift(reader(block), neighborhood, Edge::Bounded).expect("");
+            (part_1(&floor), part_2(&floor))
+        };
+        assert_eq!(answers(Neighborhood::moore()), (4, 9));
+        assert_eq!(answers(Neighborhood::von_neumann()), (8, 9));
+        assert_eq!(answers(Neighborhood::moore_radius(2)), (0, 0));
+
+        // a lopsided neighborhood: a roll going frees the rolls that counted it, not the
+        // rolls it counted
+        let lopsided = Neighborhood::custom(
+            [(0, 1), (0, 2), (1, 0), (1, 1), (0, 3)].map(|(y, x)| Point::new(y, x)),
+        );
+        let floor = forklift(
+            reader("@@@@@\n@@@@@\n@@.@@\n@@@@@"),
+            lopsided,
+            Edge::Bounded,
+        )
+        .expect("");
+        assert_eq!(part_2(&floor), 19);
+    }
+
+    #[test]
+    fn other_edges() {
+        let answers = |input, edge| {
+            let floor = forklift(reader(input), Neighborhood::moore(), edge).expect("");
+            (part_1(&floor), part_2(&floor))
+        };
+        let block = "@@@\n@@@\n@@@";
+        assert_eq!(answers(block, Edge::Outside(false)), (4, 9));
+        assert_eq!(answers(block, Edge::Outside(true)), (0, 0));
+        assert_eq!(answers(block, Edge::Wrap), (0, 0));
+        assert_eq!(answers(block, Edge::Clamp), (0, 0));
+        // wrapping joins the two ends of the row, clamping doubles up the edge cells
+        let row = "@@.@@";
+        assert_eq!(answers(row, Edge::Bounded), (4, 4));
+        assert_eq!(answers(row, Edge::Wrap), (0, 0));
+        assert_eq!(answers(row, Edge::Clamp), (0, 0));
+    }
+
+    #[test]
+    fn cascades_like_removing_by_hand() {
+        // takes every removable roll off again and again until none is left
+        let by_hand = |floor: &Floor| {
+            let mut cells = floor.cells.clone();
+            let mut removed = 0;
+            loop {
+                let removable: Vec<_> = cells
+                    .iter()
+                    .filter(|&(roll, &is_roll)| {
+                        is_roll
+                            && floor
+                                .neighborhood
+                                .around(roll)
+                                .filter(|&n| cells.get(n) == Some(&true))
+                                .count()
+                                < 4
+                    })
+                    .map(|(roll, _)| roll)
+                    .collect();
+                if removable.is_empty() {
+                    return removed;
+                }
+                removed += removable.len() as i32;
+                for roll in removable {
+                    cells[roll] = false;
+                }
+            }
+        };
+        let floors = [
+            ".@@..\n@..@.",
+            "@@.@\n.@@@\n@@..",
+            "@.@@@\n@@@.@\n.@@@@\n@@.@@",
+        ];
+        let edges = [
+            Edge::Bounded,
+            Edge::Wrap,
+            Edge::Clamp,
+            Edge::Outside(false),
+            Edge::Outside(true),
+        ];
+        for input in floors {
+            for edge in &edges {
+                let floor = forklift(reader(input), Neighborhood::moore(), edge.clone()).expect("");
+                assert_eq!(part_2(&floor), by_hand(&floor), "{input:?} with {edge:?}");
+            }
+        }
+        let clamped = forklift(reader(".@@..\n@..@."), Neighborhood::moore(), Edge::Clamp);
+        assert_eq!(part_2(&clamped.expect("")), 4);
+    }
+
+    #[test]
+    fn marks_removable_rolls() {
+        let example = include_str!("../data/examples/example.txt");
+        let floor = Day4::parse(reader(example)).expect("");
+        let marked = "\
+            ..xx.xx@x.\n\
+            x@@.@.@.@@\n\
+            @@@@@.x.@@\n\
+            @.@@@@..@.\n\
+            x@.@@@@.@x\n\
+            .@@@@@@@.@\n\
+            .@.@.@.@@@\n\
+            x.@@@.@@@@\n\
+            .@@@@@@@@.\n\
+            x.x.@@@.x.\n";
+        assert_eq!(render(&floor, false), marked);
+        assert!(render(&floor, true).contains("\x1b[48;2;38;139;210mx"));
+    }
+
+    #[test]
+    fn removes_in_layers() {
+        let example = include_str!("../data/examples/example.txt");
+        let floor = Day4::parse(reader(example)).expect("");
+        let layers = layers(&floor);
+        assert_eq!(layers.counts(), vec![13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        assert_eq!(layers.counts()[0] as i32, part_1(&floor));
+        assert_eq!(layers.total() as i32, part_2(&floor));
+        assert_eq!(layers.rounds[0][..2], [Point::new(0, 2), Point::new(0, 3)]);
+        let report = layers.to_string();
+        assert!(report.starts_with("round 1: 13 rolls\nround 2: 12 rolls\n"));
+        assert!(report.ends_with("round 9: 1 rolls\nstable after 9 rounds, 43 rolls removed"));
+        assert!(format!("{layers:#}").starts_with("round 1: 13 rolls -> 0,2 0,3 0,5 "));
+    }
+
+    #[test]
+    fn lists_rolls_on_the_floor() {
+        // rolls freed across a wrapped or clamped edge are named where they lie
+        let input = ".@@@\n@@..\n.@..";
+        for edge in [Edge::Wrap, Edge::Clamp] {
+            let floor = forklift(reader(input), Neighborhood::moore(), edge).expect("");
+            let layers = layers(&floor);
+            assert_eq!(layers.total(), 6);
+            for roll in layers.rounds.concat() {
+                assert_eq!(floor.cells.get(roll), Some(&true), "{roll:?}");
+            }
+        }
+        let wrapped = forklift(reader(input), Neighborhood::moore(), Edge::Wrap).expect("");
+        assert_eq!(
+            layers(&wrapped).rounds[1],
+            [Point::new(0, 2), Point::new(1, 0)]
+        );
+    }
+
+    #[test]
+    fn draws_a_frame_per_round() {
+        let example = include_str!("../data/examples/example.txt");
+        let floor = Day4::parse(reader(example)).expect("");
+        let frames = frames(&floor);
+        assert_eq!(frames.len(), 10);
+        let count = |frame: usize, spot| frames[frame].iter().filter(|(_, s)| **s == spot).count();
+        assert_eq!(count(0, Spot::Removing), 13);
+        assert_eq!(
+            (count(1, Spot::Removed), count(1, Spot::Removing)),
+            (13, 12)
+        );
+        assert_eq!(count(9, Spot::Removed), 43);
+    }
+
+    #[test]
+    fn peels_for_any_threshold() {
+        let example = include_str!("../data/examples/example.txt");
+        let floor = Day4::parse(reader(example)).expect("");
+        let cores = cores(&floor);
+        assert_eq!(removed_below(&cores, 4) as i32, part_2(&floor));
+        assert_eq!(removed_below(&cores, 0), 0);
+        assert_eq!(removed_below(&cores, 9), 71);
+        let block = "@@@\n@@@\n@@@";
+        for edge in [Edge::Bounded, Edge::Outside(true)] {
+            let floor = forklift(reader(block), Neighborhood::moore(), edge).expect("");
+            assert_eq!(
+                removed_below(&super::cores(&floor), 4) as i32,
+                part_2(&floor)
+            );
+        }
+    }
+}
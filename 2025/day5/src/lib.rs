@@ -0,0 +1,93 @@
+use aoc_core::{
+    AocError, Result, Solution,
+    input::{column_of, numbered_lines},
+    range::parse_range,
+};
+use rangemap::RangeInclusiveSet;
+use std::io::BufRead;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Inventory;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Inventory> {
+        freshness(reader)
+    }
+
+    fn part1(inventory: &Inventory) -> u64 {
+        part_1(inventory)
+    }
+
+    fn part2(inventory: &Inventory) -> u64 {
+        part_2(inventory)
+    }
+}
+
+#[derive(Debug)]
+pub struct Inventory {
+    /// Every ID covered by a fresh range, with overlapping ranges merged.
+    rmap: RangeInclusiveSet<i64>,
+    /// The available ingredient IDs.
+    inputs: Vec<i64>,
+}
+
+pub(crate) fn freshness(reader: impl BufRead) -> Result<Inventory> {
+    let mut rmap = RangeInclusiveSet::<i64>::new();
+    let mut next = false;
+    let mut inputs = Vec::<i64>::new();
+    for numbered in numbered_lines(reader) {
+        let (line_no, line_untrimmed) = numbered?;
+        let line = line_untrimmed.trim();
+        if line.is_empty() {
+            next = true;
+            continue;
+        }
+        let column = column_of(&line_untrimmed, line);
+        if !next {
+            rmap.insert(parse_range(line).map_err(|e| e.shifted(line_no, column))?)
+        } else {
+            inputs.push(
+                line.parse()
+                    .map_err(|e| AocError::parse(line_no, column, line, e))?,
+            );
+        }
+    }
+    Ok(Inventory { rmap, inputs })
+}
+
+pub(crate) fn part_1(inventory: &Inventory) -> u64 {
+    let mut ans = 0;
+    for i in &inventory.inputs {
+        if inventory.rmap.contains(i) {
+            ans += 1;
+        }
+    }
+    ans
+}
+
+pub(crate) fn part_2(inventory: &Inventory) -> u64 {
+    let mut ans = 0;
+    for r in inventory.rmap.iter() {
+        ans += r.end() - r.start() + 1;
+    }
+    ans as u64
+}
+
+aoc_core::example_tests!(Day5);
+
+#[cfg(test)]
+mod tests {
+    use crate::Day5;
+    use aoc_core::{Solution, input::reader};
+
+    #[test]
+    fn malformed_inventory() {
+        let err = |input| Day5::parse(reader(input)).expect_err("").position();
+        assert_eq!(err("3-5\n10-1a\n\n1"), Some((2, 4)));
+        assert_eq!(err("3-5\n\n1\n 8x"), Some((4, 2)));
+        assert_eq!(err("3-5\n  10-3\n\n4"), Some((2, 3)));
+    }
+}
@@ -0,0 +1,209 @@
+use aoc_core::{
+    AocError, Result, Solution,
+    digits::from_digits,
+    input::{column_of, numbered_lines},
+};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Equation {
+    operator: Op,
+    operands: Vec<Vec<char>>,
+}
+
+#[derive(Debug)]
+pub enum Op {
+    Add,
+    Multiply,
+}
+
+const BASE: u32 = 10;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Vec<Equation>> {
+        math(reader)
+    }
+
+    fn part1(eqs: &Vec<Equation>) -> u64 {
+        part_1(eqs)
+    }
+
+    fn part2(eqs: &Vec<Equation>) -> u64 {
+        part_2(eqs)
+    }
+}
+
+pub(crate) fn math(reader: impl BufRead) -> Result<Vec<Equation>> {
+    let mut operands: Vec<Vec<char>> = Vec::new();
+    // (column, operator) along the last line
+    let mut operators: Vec<(usize, Op)> = Vec::new();
+    let mut lines = numbered_lines(reader).peekable();
+    let mut equations: Vec<Equation> = Vec::new();
+    let mut last_line = (0, String::new());
+    while let Some(numbered) = lines.next() {
+        let (line_no, line) = numbered?;
+        let vals: Vec<char> = line.chars().collect();
+        if lines.peek().is_some() {
+            if let Some(first) = operands.first()
+                && first.len() != vals.len()
+            {
+                return Err(AocError::shape(
+                    line_no,
+                    1,
+                    &line,
+                    format!("expected {} columns like the first row", first.len()),
+                ));
+            }
+            if let Some(column) = vals.iter().position(|c| *c != ' ' && !c.is_ascii_digit()) {
+                return Err(AocError::parse(
+                    line_no,
+                    column + 1,
+                    &vals[column].to_string(),
+                    "operands must be digits",
+                ));
+            }
+            operands.push(vals);
+        } else {
+            operators = line
+                .split_whitespace()
+                .map(|n| {
+                    let column = column_of(&line, n);
+                    match n {
+                        "+" => Ok((column, Op::Add)),
+                        "*" => Ok((column, Op::Multiply)),
+                        unknown => Err(AocError::parse(
+                            line_no,
+                            column,
+                            unknown,
+                            "unsupported operator",
+                        )),
+                    }
+                })
+                .collect::<Result<_>>()?;
+            last_line = (line_no, line);
+        }
+    }
+    if operands.is_empty() {
+        let (line_no, line) = last_line;
+        return Err(AocError::shape(
+            line_no.max(1),
+            1,
+            &line,
+            "expected rows of operands above the operators",
+        ));
+    }
+    let rows = operands.len();
+    let mut index = 0;
+    for (column, o) in operators {
+        let mut acc = vec![Vec::new(); rows];
+        while let Some(slice) = next_slice(&operands, index) {
+            for j in 0..rows {
+                acc[j].push(slice[j]);
+            }
+            index += 1;
+        }
+        index += 1;
+        if acc[0].is_empty() {
+            let (line_no, line) = &last_line;
+            return Err(AocError::shape(
+                *line_no,
+                column,
+                &line.chars().skip(column - 1).collect::<String>(),
+                "more operators than problems",
+            ));
+        }
+        equations.push(Equation {
+            operator: o,
+            operands: acc,
+        });
+    }
+    if index < operands[0].len() {
+        let (line_no, line) = &last_line;
+        return Err(AocError::shape(
+            *line_no,
+            line.chars().count() + 1,
+            "",
+            "fewer operators than problems",
+        ));
+    }
+    Ok(equations)
+}
+
+fn next_slice(input: &[Vec<char>], index: usize) -> Option<Vec<char>> {
+    if index >= input[0].len() {
+        return None;
+    }
+    let rows = input.len();
+    let mut all_spaces = true;
+    let mut tmp = Vec::with_capacity(rows);
+    for row in input {
+        let x = &row[index];
+        tmp.push(*x);
+        if *x != ' ' {
+            all_spaces = false;
+        }
+    }
+    if all_spaces { None } else { Some(tmp) }
+}
+
+pub(crate) fn part_1(eqs: &[Equation]) -> u64 {
+    let mut ans = 0;
+    for eq in eqs {
+        let nums = eq.operands.iter().map(|l| {
+            l.iter()
+                .filter_map(|&n| n.to_digit(BASE))
+                .collect::<Vec<u32>>()
+        });
+        match eq.operator {
+            Op::Multiply => ans += nums.fold(1, |acc, x| acc * from_digits(&x)),
+            Op::Add => ans += nums.fold(0, |acc, x| acc + from_digits(&x)),
+        }
+    }
+    ans
+}
+
+pub(crate) fn part_2(eqs: &[Equation]) -> u64 {
+    let mut ans = 0;
+    for eq in eqs {
+        let mut nums: Vec<Vec<u32>> = Vec::new();
+        for i in 0..eq.operands[0].len() {
+            let mut n: Vec<u32> = Vec::new();
+            for j in 0..eq.operands.len() {
+                if let Some(v) = eq.operands[j][i].to_digit(BASE) {
+                    n.push(v)
+                }
+            }
+            nums.push(n);
+        }
+        match eq.operator {
+            Op::Multiply => ans += nums.iter().fold(1, |acc, x| acc * from_digits(x)),
+            Op::Add => ans += nums.iter().fold(0, |acc, x| acc + from_digits(x)),
+        }
+    }
+    ans
+}
+
+aoc_core::example_tests!(Day6);
+
+#[cfg(test)]
+mod tests {
+    use crate::Day6;
+    use aoc_core::{Solution, input::reader};
+
+    #[test]
+    fn malformed_worksheet() {
+        let err = |input| Day6::parse(reader(input)).expect_err("").position();
+        assert_eq!(err("12 3\n4  5\n+  -"), Some((3, 4)));
+        assert_eq!(err("12 3\n4 5\n+  *"), Some((2, 1)));
+        assert_eq!(err("12 3\n4x 5\n+  *"), Some((2, 2)));
+        assert_eq!(err("12 3\n4  5\n+  *  +"), Some((3, 7)));
+        assert_eq!(err("12 3\n4  5\n+"), Some((3, 2)));
+        assert_eq!(err("+ *"), Some((1, 1)));
+    }
+}
@@ -0,0 +1,23 @@
+//! Draws a manifold, by default the day's own input, optionally with `|` along every beam as
+//! the puzzle draws them, shaded by how many timelines pass:
+//!
+//!     cargo run -p day7 --example beams [--beams] [input | -]
+
+use aoc_core::{cli, render::stdout_has_color};
+use day7::{render, teleport};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cli::run(
+        env!("CARGO_MANIFEST_DIR"),
+        &["--beams"],
+        teleport,
+        |args, manifold| {
+            print!(
+                "{}",
+                render(&manifold, args.switch("--beams"), stdout_has_color())
+            );
+            Ok(())
+        },
+    )
+}
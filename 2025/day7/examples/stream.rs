@@ -0,0 +1,21 @@
+//! Counts splits and timelines a row at a time, reading the manifold from a file or, given `-`,
+//! from standard input, so it need never fit in memory:
+//!
+//!     cargo run -p day7 --example stream [input | -]
+
+use aoc_core::{big::BigUint, cli};
+use day7::stream;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cli::run(
+        env!("CARGO_MANIFEST_DIR"),
+        &[],
+        stream::<BigUint>,
+        |_, (splits, timelines)| {
+            println!("Splits: {splits}");
+            println!("Timelines: {timelines}");
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,27 @@
+//! Traces beams through a manifold that may hold mirrors `/` and `\`, absorbers `#`, deflectors
+//! `<` and `>`, and any number of `S` sources:
+//!
+//!     cargo run -p day7 --example trace [input | -]
+
+use aoc_core::{big::BigUint, cli};
+use day7::elements::Elements;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let elements = Elements::extended();
+    cli::run(
+        env!("CARGO_MANIFEST_DIR"),
+        &[],
+        |input| elements.parse(input),
+        |_, manifold| {
+            let trace = elements.trace::<BigUint>(&manifold)?;
+            println!("Splits: {}", trace.splits);
+            println!("Lit: {}", trace.lit.iter().filter(|(_, lit)| **lit).count());
+            match trace.timelines {
+                Some(timelines) => println!("Timelines: {timelines}"),
+                None => println!("Timelines: endless, a beam goes round a loop"),
+            }
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,421 @@
+use crate::Tally;
+use aoc_core::{
+    AocError, Result,
+    grid::Grid,
+    point::{Direction, Point},
+};
+use std::{collections::HashMap, io::BufRead};
+
+/// A way a beam can travel: beams only ever go north, east, south or west. Numbered clockwise
+/// from north like [`Heading::ALL`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Heading {
+    N = 0,
+    E = 1,
+    S = 2,
+    W = 3,
+}
+
+impl Heading {
+    /// North, east, south and west, clockwise.
+    pub const ALL: [Heading; 4] = [Heading::N, Heading::E, Heading::S, Heading::W];
+
+    /// The step one cell this way.
+    pub fn offset(self) -> Point<i32> {
+        Direction::from(self).offset()
+    }
+
+    /// The heading a quarter turn clockwise.
+    pub fn turn_right(self) -> Heading {
+        Heading::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The heading a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Heading {
+        Heading::ALL[(self as usize + 3) % 4]
+    }
+}
+
+impl From<Heading> for Direction {
+    fn from(heading: Heading) -> Direction {
+        match heading {
+            Heading::N => Direction::N,
+            Heading::E => Direction::E,
+            Heading::S => Direction::S,
+            Heading::W => Direction::W,
+        }
+    }
+}
+
+/// A beam leaving a cell: the side of the cell it comes out of and the way it heads from there.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Exit {
+    pub side: Heading,
+    pub heading: Heading,
+}
+
+impl Exit {
+    /// Straight out of the cell heading `heading`.
+    pub fn toward(heading: Heading) -> Exit {
+        Exit {
+            side: heading,
+            heading,
+        }
+    }
+}
+
+/// What a kind of cell does to a beam entering it, as the beams leaving for each way it could be
+/// heading.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Behavior {
+    /// Indexed like [`Heading::ALL`].
+    exits: [Vec<Exit>; 4],
+}
+
+impl Behavior {
+    /// Looks up the beams leaving for a beam heading each way with `exits`.
+    pub fn new<F>(exits: F) -> Behavior
+    where
+        F: FnMut(Heading) -> Vec<Exit>,
+    {
+        Behavior {
+            exits: Heading::ALL.map(exits),
+        }
+    }
+
+    /// Lets beams through untouched.
+    pub fn pass() -> Behavior {
+        Behavior::new(|heading| vec![Exit::toward(heading)])
+    }
+
+    /// Stops every beam.
+    pub fn absorb() -> Behavior {
+        Behavior::new(|_| Vec::new())
+    }
+
+    /// Turns beams to head the way `turn` says.
+    pub fn turn<F>(turn: F) -> Behavior
+    where
+        F: Fn(Heading) -> Heading,
+    {
+        Behavior::new(|heading| vec![Exit::toward(turn(heading))])
+    }
+
+    /// Splits a beam in two, carrying on the same way out of the cells either side, as `^` does.
+    pub fn split() -> Behavior {
+        Behavior::new(|heading| {
+            [heading.turn_left(), heading.turn_right()]
+                .map(|side| Exit { side, heading })
+                .to_vec()
+        })
+    }
+
+    /// The beams leaving for one heading `heading`.
+    pub fn exits(&self, heading: Heading) -> &[Exit] {
+        &self.exits[heading as usize]
+    }
+}
+
+/// The kinds of cell a manifold can hold, by glyph, and which of them send out beams.
+#[derive(Clone, Debug)]
+pub struct Elements {
+    behaviors: HashMap<char, Behavior>,
+    /// Glyphs sending a beam off when the manifold starts, with the way it heads.
+    sources: HashMap<char, Heading>,
+}
+
+impl Elements {
+    /// The puzzle's own cells: `S` sends a beam south, `^` splits it and `.` is empty.
+    pub fn puzzle() -> Elements {
+        Elements {
+            behaviors: HashMap::new(),
+            sources: HashMap::new(),
+        }
+        .with_source('S', Heading::S)
+        .with('^', Behavior::split())
+        .with('.', Behavior::pass())
+    }
+
+    /// The puzzle's cells along with mirrors `/` and `\`, absorbers `#`, and deflectors `<` and
+    /// `>` sending every beam west or east.
+    pub fn extended() -> Elements {
+        use Heading::{E, N, S, W};
+        Elements::puzzle()
+            .with(
+                '/',
+                Behavior::turn(|d| match d {
+                    N => E,
+                    E => N,
+                    S => W,
+                    _ => S,
+                }),
+            )
+            .with(
+                '\\',
+                Behavior::turn(|d| match d {
+                    N => W,
+                    W => N,
+                    S => E,
+                    _ => S,
+                }),
+            )
+            .with('#', Behavior::absorb())
+            .with('<', Behavior::turn(|_| W))
+            .with('>', Behavior::turn(|_| E))
+    }
+
+    /// Adds or replaces the cell drawn as `glyph`.
+    pub fn with(mut self, glyph: char, behavior: Behavior) -> Elements {
+        self.behaviors.insert(glyph, behavior);
+        self
+    }
+
+    /// Makes `glyph` send a beam off `heading` when the manifold starts. Beams crossing it later
+    /// pass through unless it was given another behavior.
+    pub fn with_source(mut self, glyph: char, heading: Heading) -> Elements {
+        self.behaviors.entry(glyph).or_insert_with(Behavior::pass);
+        self.sources.insert(glyph, heading);
+        self
+    }
+
+    /// Reads a manifold made of these cells.
+    pub fn parse(&self, reader: impl BufRead) -> Result<Grid<char>> {
+        Grid::parse(reader, |c| self.behaviors.contains_key(&c).then_some(c))
+    }
+
+    /// Follows every beam from every source through `manifold` until it leaves the manifold, is
+    /// absorbed, or joins a beam already traced. Fails on a cell these elements do not know,
+    /// as a manifold read some other way may hold.
+    pub fn trace<T: Tally>(&self, manifold: &Grid<char>) -> Result<Trace<T>> {
+        if let Some((p, glyph)) = manifold
+            .iter()
+            .find(|(_, glyph)| !self.behaviors.contains_key(glyph))
+        {
+            return Err(AocError::parse(
+                p.y as usize + 1,
+                p.x as usize + 1,
+                &glyph.to_string(),
+                "not a cell of these elements",
+            ));
+        }
+        Ok(Tracer::new(self, manifold).run())
+    }
+}
+
+/// Where the beams went.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace<T> {
+    /// The cells some beam passed through.
+    pub lit: Grid<bool>,
+    /// How many times a beam entering a cell left it as more than one.
+    pub splits: u64,
+    /// How many ways a particle from any source can go, where each split sends one way each
+    /// way, counting those that leave the manifold and those absorbed. `None` when some way
+    /// comes back round to where it has been, so goes on forever.
+    pub timelines: Option<T>,
+}
+
+impl<T> Trace<T> {
+    pub fn looped(&self) -> bool {
+        self.timelines.is_none()
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Mark {
+    Unseen,
+    /// Being followed: a beam reaching it again has gone round a loop.
+    Open,
+    Done,
+}
+
+/// A depth first walk over beams, each a cell and the way the beam in it heads, that adds up
+/// the timelines from each beam once every beam after it is done.
+struct Tracer<'a, T> {
+    elements: &'a Elements,
+    manifold: &'a Grid<char>,
+    /// Indexed by cell index times four plus heading, like [`Heading::ALL`].
+    marks: Vec<Mark>,
+    timelines: Vec<T>,
+    lit: Grid<bool>,
+    splits: u64,
+    looped: bool,
+}
+
+impl<'a, T: Tally> Tracer<'a, T> {
+    fn new(elements: &'a Elements, manifold: &'a Grid<char>) -> Self {
+        let beams = manifold.width() * manifold.height() * 4;
+        Tracer {
+            elements,
+            manifold,
+            marks: vec![Mark::Unseen; beams],
+            timelines: vec![T::zero(); beams],
+            lit: manifold.map(|_, _| false),
+            splits: 0,
+            looped: false,
+        }
+    }
+
+    fn beam(&self, p: Point<i32>, heading: Heading) -> usize {
+        let cell = self
+            .manifold
+            .index_of(p)
+            .expect("beams stay in the manifold");
+        cell * 4 + heading as usize
+    }
+
+    fn exits(&self, beam: usize) -> (Point<i32>, &'a [Exit]) {
+        let p = self.manifold.point_of(beam / 4);
+        let behavior = &self.elements.behaviors[&self.manifold[p]];
+        (p, behavior.exits(Heading::ALL[beam % 4]))
+    }
+
+    fn run(mut self) -> Trace<T> {
+        let mut total = T::zero();
+        let sources: Vec<usize> = self
+            .manifold
+            .iter()
+            .filter_map(|(p, glyph)| self.elements.sources.get(glyph).map(|&d| (p, d)))
+            .map(|(p, heading)| self.beam(p, heading))
+            .collect();
+        for source in sources {
+            self.follow(source);
+            total.add(&self.timelines[source]);
+        }
+        Trace {
+            lit: self.lit,
+            splits: self.splits,
+            timelines: (!self.looped).then_some(total),
+        }
+    }
+
+    fn follow(&mut self, start: usize) {
+        if self.marks[start] != Mark::Unseen {
+            return;
+        }
+        self.open(start);
+        let mut stack = vec![(start, 0)];
+        while let Some(&mut (beam, ref mut next)) = stack.last_mut() {
+            let (p, exits) = self.exits(beam);
+            let Some(exit) = exits.get(*next) else {
+                if exits.is_empty() {
+                    self.timelines[beam] = T::one();
+                } else if exits.len() > 1 {
+                    self.splits += 1;
+                }
+                self.marks[beam] = Mark::Done;
+                stack.pop();
+                if let Some(&(from, _)) = stack.last() {
+                    let timelines = self.timelines[beam].clone();
+                    self.timelines[from].add(&timelines);
+                }
+                continue;
+            };
+            *next += 1;
+            let to = p + exit.side.offset();
+            if !self.manifold.contains(to) {
+                self.timelines[beam].add(&T::one());
+                continue;
+            }
+            let to = self.beam(to, exit.heading);
+            match self.marks[to] {
+                Mark::Unseen => {
+                    self.open(to);
+                    stack.push((to, 0));
+                }
+                Mark::Open => self.looped = true,
+                Mark::Done => {
+                    let timelines = self.timelines[to].clone();
+                    self.timelines[beam].add(&timelines);
+                }
+            }
+        }
+    }
+
+    fn open(&mut self, beam: usize) {
+        self.marks[beam] = Mark::Open;
+        let p = self.manifold.point_of(beam / 4);
+        self.lit[p] = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Day7,
+        elements::{Behavior, Elements, Heading},
+    };
+    use aoc_core::{
+        Solution,
+        big::BigUint,
+        grid::Grid,
+        input::reader,
+        point::{Direction, Point},
+    };
+
+    #[test]
+    fn matches_the_puzzle() {
+        let example = include_str!("../data/examples/example.txt");
+        let manifold = Elements::puzzle().parse(reader(example)).expect("");
+        let trace = Elements::puzzle().trace::<BigUint>(&manifold).expect("");
+        let counts = Day7::parse(reader(example)).expect("");
+        assert_eq!(trace.splits, counts.splits);
+        assert_eq!(trace.timelines, Some(counts.timelines));
+        assert!(Elements::puzzle().parse(reader("S/")).is_err());
+    }
+
+    #[test]
+    fn absorbs_and_deflects() {
+        let elements = Elements::extended();
+        let manifold = elements.parse(reader(".S.S\n.^..\n#..<")).expect("");
+        let trace = elements.trace::<u128>(&manifold).expect("");
+        // one beam absorbed from each side of the splitter, one off the bottom
+        assert_eq!((trace.splits, trace.timelines), (1, Some(3)));
+        assert_eq!(trace.lit.iter().filter(|(_, lit)| **lit).count(), 10);
+        assert!(!trace.lit[Point::new(0, 0)]);
+    }
+
+    #[test]
+    fn stops_going_round_mirrors() {
+        let elements = Elements::extended();
+        let manifold = elements.parse(reader("/..\\\nS...\n\\../")).expect("");
+        let trace = elements.trace::<u128>(&manifold).expect("");
+        assert!(trace.looped());
+        assert_eq!(trace.lit.iter().filter(|(_, lit)| **lit).count(), 10);
+    }
+
+    #[test]
+    fn takes_new_cells() {
+        let elements = Elements::extended()
+            .with('|', Behavior::absorb())
+            .with_source('e', Heading::E);
+        let manifold = elements.parse(reader("e.|.\n.S..")).expect("");
+        let trace = elements.trace::<u128>(&manifold).expect("");
+        assert_eq!(trace.timelines, Some(2));
+        assert_eq!(trace.lit.iter().filter(|(_, lit)| **lit).count(), 4);
+    }
+
+    #[test]
+    fn rejects_unknown_cells() {
+        // read by a wider set of elements, or as a plain grid
+        let manifold = Elements::extended().parse(reader(".S.\n./.")).expect("");
+        let err = Elements::puzzle().trace::<u128>(&manifold).expect_err("");
+        assert_eq!(err.position(), Some((2, 2)));
+        let manifold = Grid::parse(reader("S\n?"), Some).expect("");
+        let err = Elements::puzzle().trace::<u128>(&manifold).expect_err("");
+        assert_eq!(err.position(), Some((2, 1)));
+    }
+
+    #[test]
+    fn headings_turn_like_directions() {
+        for heading in Heading::ALL {
+            let direction = Direction::from(heading);
+            assert_eq!(
+                Direction::from(heading.turn_right()),
+                direction.turn_right()
+            );
+            assert_eq!(Direction::from(heading.turn_left()), direction.turn_left());
+            assert_eq!(heading.offset(), direction.offset());
+        }
+    }
+}
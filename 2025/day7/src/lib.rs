@@ -0,0 +1,411 @@
+use aoc_core::{
+    Result, Solution,
+    big::BigUint,
+    grid::{Grid, parse_rows},
+    render::{self, Rgb, Style},
+};
+use std::io::BufRead;
+
+pub mod elements;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Counts;
+    type Part1 = u64;
+    type Part2 = BigUint;
+
+    fn parse(reader: impl BufRead) -> Result<Counts> {
+        let (splits, timelines) = stream(reader)?;
+        Ok(Counts { splits, timelines })
+    }
+
+    fn part1(counts: &Counts) -> u64 {
+        counts.splits
+    }
+
+    fn part2(counts: &Counts) -> BigUint {
+        counts.timelines.clone()
+    }
+}
+
+/// Both answers, counted while the manifold is read a row at a time so it is never held whole.
+/// Timelines are a [`BigUint`], as a deep enough manifold has more than fit in a `u128`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Counts {
+    pub splits: u64,
+    pub timelines: BigUint,
+}
+
+/// One cell of the tachyon manifold.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Cell {
+    /// `S`, where the beam enters.
+    Start,
+    /// `^`, which splits a beam into one on either side.
+    Splitter,
+    /// `.`
+    Empty,
+}
+
+/// Reads the whole manifold, for the views that need every cell at once, such as [`traffic`]
+/// and [`render`]. The solver itself only ever holds a row.
+pub fn teleport(reader: impl BufRead) -> Result<Grid<Cell>> {
+    Grid::parse(reader, cell)
+}
+
+fn cell(c: char) -> Option<Cell> {
+    match c {
+        'S' => Some(Cell::Start),
+        '^' => Some(Cell::Splitter),
+        '.' => Some(Cell::Empty),
+        _ => None,
+    }
+}
+
+/// Sends a beam down from every `S`, splitting it at each `^` it meets into beams in the
+/// columns either side, and counts the splits. Beams landing in the same column merge.
+pub fn splits(manifold: &Grid<Cell>) -> u64 {
+    Flow::<()>::through(manifold).splits
+}
+
+/// How many timelines a particle dropped in at any `S` can follow, where every `^` it meets
+/// sends one timeline each way. A timeline split off the side of the manifold ends there,
+/// still counted.
+pub fn timelines<T: Tally>(manifold: &Grid<Cell>) -> T {
+    Flow::through(manifold).timelines()
+}
+
+/// Both answers for a manifold read a row at a time from `reader`, holding only the current row
+/// and the counts below it, so the manifold can be far taller than would fit in memory.
+pub fn stream<T: Tally>(reader: impl BufRead) -> Result<(u64, T)> {
+    let mut flow: Option<Flow<T>> = None;
+    for row in parse_rows(reader, cell) {
+        let row = row?;
+        flow.get_or_insert_with(|| Flow::new(row.len())).step(&row);
+    }
+    Ok(flow.map_or((0, T::zero()), |flow| (flow.splits, flow.timelines())))
+}
+
+/// What passes through one cell of a manifold.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Traffic<T> {
+    /// Beams entering the cell, from above, from splitters either side, or from an `S`. Beams
+    /// meeting there merge into one, but each is counted.
+    pub beams: u32,
+    pub timelines: T,
+}
+
+/// How many beams and timelines pass through each cell of `manifold`. A `^` counts what reaches
+/// it from above before sending it aside.
+pub fn traffic<T: Tally>(manifold: &Grid<Cell>) -> Grid<Traffic<T>> {
+    let mut flow = Flow::<T>::new(manifold.width());
+    let mut cells = Vec::with_capacity(manifold.width() * manifold.height());
+    for row in manifold.rows() {
+        let (beams, timelines) = (flow.beams.clone(), flow.timelines.clone());
+        flow.step(row);
+        let split_onto = |side: Option<usize>| {
+            side.is_some_and(|s| row.get(s) == Some(&Cell::Splitter) && beams[s]) as u32
+        };
+        for (x, cell) in row.iter().enumerate() {
+            cells.push(match cell {
+                Cell::Splitter => Traffic {
+                    beams: beams[x] as u32,
+                    timelines: timelines[x].clone(),
+                },
+                _ => Traffic {
+                    beams: beams[x] as u32
+                        + (*cell == Cell::Start) as u32
+                        + split_onto(x.checked_sub(1))
+                        + split_onto(Some(x + 1)),
+                    timelines: flow.timelines[x].clone(),
+                },
+            });
+        }
+    }
+    Grid::from_cells(cells, manifold.width())
+}
+
+/// Draws `manifold`, with `beams` marking every empty cell a beam passes through `|` as the
+/// puzzle does. In `color` those are shaded from blue to red by how many timelines pass.
+pub fn render(manifold: &Grid<Cell>, beams: bool, color: bool) -> String {
+    let traffic = traffic::<f64>(manifold);
+    let most = traffic
+        .iter()
+        .map(|(_, t)| t.timelines)
+        .fold(2.0, f64::max)
+        .ln();
+    let beam = |p| {
+        let through = &traffic[p];
+        (beams && manifold[p] == Cell::Empty && through.beams > 0).then(|| Style {
+            glyph: Some('|'),
+            fg: Some(Rgb::BLUE.lerp(Rgb::RED, through.timelines.ln() / most)),
+            ..Style::default()
+        })
+    };
+    let glyph = |cell: &Cell| match cell {
+        Cell::Start => 'S',
+        Cell::Splitter => '^',
+        Cell::Empty => '.',
+    };
+    render::render(manifold, glyph, &[&beam], color)
+}
+
+/// What reaches the bottom of the rows so far: which columns carry a beam, how many timelines
+/// each holds, and how many beams have split on the way.
+struct Flow<T> {
+    beams: Vec<bool>,
+    timelines: Vec<T>,
+    /// Timelines split off the side of the manifold.
+    ended: T,
+    splits: u64,
+}
+
+impl<T: Tally> Flow<T> {
+    fn new(width: usize) -> Self {
+        Flow {
+            beams: vec![false; width],
+            timelines: vec![T::zero(); width],
+            ended: T::zero(),
+            splits: 0,
+        }
+    }
+
+    fn through(manifold: &Grid<Cell>) -> Self {
+        let mut flow = Flow::new(manifold.width());
+        for row in manifold.rows() {
+            flow.step(row);
+        }
+        flow
+    }
+
+    /// Carries the beams and timelines down through `row`.
+    fn step(&mut self, row: &[Cell]) {
+        let width = self.beams.len();
+        let mut beams = vec![false; width];
+        let mut timelines = vec![T::zero(); width];
+        for (x, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Start => {
+                    beams[x] = true;
+                    timelines[x].add(&self.timelines[x]);
+                    timelines[x].add(&T::one());
+                }
+                Cell::Splitter => {
+                    if self.beams[x] {
+                        self.splits += 1;
+                    }
+                    for side in [x.checked_sub(1), Some(x + 1).filter(|&s| s < width)] {
+                        match side {
+                            Some(s) => {
+                                beams[s] |= self.beams[x];
+                                timelines[s].add(&self.timelines[x]);
+                            }
+                            None => self.ended.add(&self.timelines[x]),
+                        }
+                    }
+                }
+                Cell::Empty => {
+                    beams[x] |= self.beams[x];
+                    timelines[x].add(&self.timelines[x]);
+                }
+            }
+        }
+        self.beams = beams;
+        self.timelines = timelines;
+    }
+
+    fn timelines(&self) -> T {
+        self.timelines
+            .iter()
+            .fold(self.ended.clone(), |mut total, count| {
+                total.add(count);
+                total
+            })
+    }
+}
+
+/// A number of timelines, which only ever needs adding up.
+pub trait Tally: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&mut self, other: &Self);
+}
+
+impl Tally for u128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self = self
+            .checked_add(*other)
+            .expect("more timelines than fit in a u128; count them as a BigUint");
+    }
+}
+
+/// Counts nothing, for when only the beams matter.
+impl Tally for () {
+    fn zero() -> Self {}
+
+    fn one() -> Self {}
+
+    fn add(&mut self, _: &Self) {}
+}
+
+/// Counts roughly but never overflows, for shading by how many there are.
+impl Tally for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self += other;
+    }
+}
+
+impl Tally for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn one() -> Self {
+        BigUint::from(1u64)
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self += other;
+    }
+}
+
+aoc_core::example_tests!(Day7);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cell, Day7, Traffic, render, splits, stream, teleport, timelines, traffic};
+    use aoc_core::point::Point;
+    use aoc_core::{Solution, big::BigUint, grid::Grid, input::reader};
+
+    /// A splitter under every timeline on each of `depth` levels, doubling them every time.
+    fn doubling(depth: usize) -> Grid<Cell> {
+        teleport(reader(&doubling_text(depth))).expect("")
+    }
+
+    fn doubling_text(depth: usize) -> String {
+        let mut manifold = format!("{0}S{0}\n", ".".repeat(depth));
+        for level in 0..depth {
+            for x in 0..=2 * depth {
+                let offset = x.abs_diff(depth);
+                let split = offset <= level && offset % 2 == level % 2;
+                manifold.push(if split { '^' } else { '.' });
+            }
+            manifold.push('\n');
+        }
+        manifold
+    }
+
+    #[test]
+    fn counts_past_u128() {
+        assert_eq!(timelines::<u128>(&doubling(127)), 1 << 127);
+        let mut expected = BigUint::from(1u64);
+        for _ in 0..130 {
+            expected = expected.clone() + expected;
+        }
+        assert_eq!(timelines::<BigUint>(&doubling(130)), expected);
+    }
+
+    #[test]
+    fn solves_past_u128() {
+        let deep = Day7::parse(reader(&doubling_text(140))).expect("");
+        let mut expected = BigUint::from(1u64);
+        for _ in 0..140 {
+            expected = expected.clone() + expected;
+        }
+        assert_eq!(Day7::part2(&deep), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "count them as a BigUint")]
+    fn refuses_to_overflow() {
+        timelines::<u128>(&doubling(128));
+    }
+
+    #[test]
+    fn streams_like_the_whole_grid() {
+        let example = include_str!("../data/examples/example.txt");
+        assert_eq!(stream::<u128>(reader(example)).expect(""), (21, 40));
+
+        // ten thousand rows of splitters cycling under three starts
+        let mut tall = String::from("..S...S..S..\n");
+        let rows = [
+            ".^..^...^.^.",
+            "............",
+            "^.^..^.^..^^",
+            "..S.........",
+        ];
+        for y in 0..10_000 {
+            tall.push_str(rows[y % rows.len()]);
+            tall.push('\n');
+        }
+        let manifold = teleport(reader(&tall)).expect("");
+        let whole = (splits(&manifold), timelines::<BigUint>(&manifold));
+        assert_eq!(stream::<BigUint>(reader(&tall)).expect(""), whole);
+
+        assert!(stream::<u128>(reader("..S..\n.^.\n")).is_err());
+        assert_eq!(stream::<u128>(reader("")).expect(""), (0, 0));
+    }
+
+    #[test]
+    fn counts_traffic_per_cell() {
+        let example = include_str!("../data/examples/example.txt");
+        let manifold = teleport(reader(example)).expect("");
+        let traffic = traffic::<u128>(&manifold);
+        let start = Traffic {
+            beams: 1,
+            timelines: 1,
+        };
+        assert_eq!(traffic[Point::new(0, 7)], start);
+        assert_eq!(traffic[Point::new(2, 7)], start);
+        // two beams meet between the second row of splitters
+        let met = &traffic[Point::new(4, 7)];
+        assert_eq!((met.beams, met.timelines), (2, 2));
+        let bottom: u128 = traffic.row(15).iter().map(|t| t.timelines).sum();
+        assert_eq!(bottom, timelines(&manifold));
+        assert_eq!(traffic[Point::new(1, 0)], Traffic::default());
+    }
+
+    #[test]
+    fn draws_beams() {
+        let example = include_str!("../data/examples/example.txt");
+        let manifold = teleport(reader(example)).expect("");
+        let drawn = "\
+.......S.......
+.......|.......
+......|^|......
+......|.|......
+.....|^|^|.....
+.....|.|.|.....
+....|^|^|^|....
+....|.|.|.|....
+...|^|^|||^|...
+...|.|.|||.|...
+..|^|^|||^|^|..
+..|.|.|||.|.|..
+.|^|||^||.||^|.
+.|.|||.||.||.|.
+|^|^|^|^|^|||^|
+|.|.|.|.|.|||.|
+";
+        assert_eq!(render(&manifold, true, false), drawn);
+        assert_eq!(render(&manifold, false, false), example.trim_start());
+        assert!(render(&manifold, true, true).contains("\x1b[38;2;38;139;210m|"));
+    }
+}